@@ -0,0 +1,238 @@
+use std::{fmt::Write as _, fs, io::{self, Write}, path::{Path, PathBuf}, time::{Duration, SystemTime, UNIX_EPOCH}};
+
+use serde_json::{json, Value};
+
+use crate::{parse::ParseError, report::get_env, solution::{Answer, Solver}};
+
+pub struct Options {
+    pub warmup: usize,
+    pub runs: usize,
+    /// Percentage increase of the median over the previous run that counts as a regression.
+    pub threshold: f64,
+    pub history: PathBuf,
+    pub save: bool
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            warmup: 3,
+            runs: 20,
+            threshold: 10.0,
+            history: Path::new(env!("CARGO_MANIFEST_DIR")).join("bench-history.jsonl"),
+            save: true
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64
+}
+
+impl Stats {
+    /// All values are in nanoseconds. `samples` must not be empty.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_by(|a, b| a.total_cmp(b));
+
+        let n = nanos.len();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let median = (nanos[(n - 1) / 2] + nanos[n / 2]) / 2.0;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Stats { min: nanos[0], median, mean, stddev: variance.sqrt() }
+    }
+}
+
+/// Statistics for one stage (`parse`, `part_1` or `part_2`) of one day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub timestamp: u64,
+    pub profile: String,
+    pub year: u16,
+    pub day: u8,
+    pub stage: String,
+    pub runs: usize,
+    pub stats: Stats
+}
+
+impl Record {
+    fn to_json(&self) -> Value {
+        json!({
+            "timestamp": self.timestamp,
+            "profile": self.profile,
+            "year": self.year,
+            "day": self.day,
+            "stage": self.stage,
+            "runs": self.runs,
+            "min_ns": self.stats.min,
+            "median_ns": self.stats.median,
+            "mean_ns": self.stats.mean,
+            "stddev_ns": self.stats.stddev
+        })
+    }
+
+    fn from_json(value: &Value) -> Option<Record> {
+        Some(Record {
+            timestamp: value["timestamp"].as_u64()?,
+            profile: value["profile"].as_str()?.to_string(),
+            year: value["year"].as_u64()? as u16,
+            day: value["day"].as_u64()? as u8,
+            stage: value["stage"].as_str()?.to_string(),
+            runs: value["runs"].as_u64()? as usize,
+            stats: Stats {
+                min: value["min_ns"].as_f64()?,
+                median: value["median_ns"].as_f64()?,
+                mean: value["mean_ns"].as_f64()?,
+                stddev: value["stddev_ns"].as_f64()?
+            }
+        })
+    }
+
+    fn same_stage(&self, other: &Record) -> bool {
+        self.profile == other.profile && self.year == other.year && self.day == other.day && self.stage == other.stage
+    }
+}
+
+/// Runs every stage of a day `warmup` times, then `runs` times while collecting timings.
+/// Parts without an answer are left out.
+pub fn bench_day(year: u16, day: u8, solve: Solver, input: &str, options: &Options) -> Result<Vec<Record>, ParseError> {
+    for _ in 0..options.warmup {
+        solve(input, &[1, 2], false)?;
+    }
+
+    let mut parse = vec![];
+    let mut parts: Vec<(u8, Vec<Duration>)> = vec![(1, vec![]), (2, vec![])];
+    for _ in 0..options.runs.max(1) {
+        let solved = solve(input, &[1, 2], false)?;
+        parse.push(solved.parse);
+        for stage in solved.parts.iter().filter(|s| s.answer != Answer::None) {
+            if let Some((_, samples)) = parts.iter_mut().find(|(p, _)| *p == stage.part) {
+                samples.push(stage.elapsed);
+            }
+        }
+    }
+
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let record = |stage: String, samples: &[Duration]| Record {
+        timestamp,
+        profile: get_env().to_string(),
+        year,
+        day,
+        stage,
+        runs: samples.len(),
+        stats: Stats::from_samples(samples)
+    };
+
+    Ok(std::iter::once(record("parse".to_string(), &parse))
+        .chain(parts.iter()
+            .filter(|(_, samples)| !samples.is_empty())
+            .map(|(part, samples)| record(format!("part_{}", part), samples)))
+        .collect())
+}
+
+/// Reads the history file. A missing file is an empty history; unreadable lines are skipped.
+pub fn load_history(path: &Path) -> io::Result<Vec<Record>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e)
+    };
+
+    Ok(contents.lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .filter_map(|value| Record::from_json(&value))
+        .collect())
+}
+
+pub fn append_history(path: &Path, records: &[Record]) -> io::Result<()> {
+    let mut file = fs::OpenOptions::new().create(true).append(true).open(path)?;
+    for record in records {
+        writeln!(file, "{}", record.to_json())?;
+    }
+
+    Ok(())
+}
+
+/// Change of the median against the latest earlier record of the same stage and profile, in percent.
+pub fn change(record: &Record, history: &[Record]) -> Option<f64> {
+    history.iter()
+        .rev()
+        .find(|previous| previous.same_stage(record))
+        .filter(|previous| previous.stats.median > 0.0)
+        .map(|previous| (record.stats.median / previous.stats.median - 1.0) * 100.0)
+}
+
+/// Formats the records as a table. Returns the table and the number of regressions.
+pub fn render(records: &[Record], history: &[Record], threshold: f64) -> (String, usize) {
+    let mut regressions = 0;
+    let mut output = format!("{:>4}  {:>3}  {:<6}  {:>12}  {:>12}  {:>12}  {:>12}  vs last\n",
+        "year", "day", "stage", "min (ns)", "median (ns)", "mean (ns)", "stddev (ns)");
+
+    for record in records {
+        let versus = match change(record, history) {
+            Some(pct) if pct > threshold => {
+                regressions += 1;
+                format!("{:+.1}% REGRESSION", pct)
+            },
+            Some(pct) => format!("{:+.1}%", pct),
+            None => "-".to_string()
+        };
+        writeln!(output, "{:>4}  {:>3}  {:<6}  {:>12.0}  {:>12.0}  {:>12.0}  {:>12.0}  {}",
+            record.year, record.day, record.stage,
+            record.stats.min, record.stats.median, record.stats.mean, record.stats.stddev, versus).unwrap();
+    }
+    write!(output, "profile: {}", get_env()).unwrap();
+
+    (output, regressions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(timestamp: u64, stage: &str, median: f64) -> Record {
+        Record {
+            timestamp,
+            profile: "RELEASE".into(),
+            year: 2022,
+            day: 20,
+            stage: stage.into(),
+            runs: 5,
+            stats: Stats { min: median, median, mean: median, stddev: 0.0 }
+        }
+    }
+
+    #[test]
+    fn stats() {
+        let samples: Vec<Duration> = [4, 1, 3, 2].iter().map(|&n| Duration::from_nanos(n)).collect();
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.median, 2.5);
+        assert_eq!(stats.mean, 2.5);
+        assert!((stats.stddev - 1.25_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn compares_with_latest_matching_record() {
+        let history = vec![record(1, "part_1", 100.0), record(2, "part_1", 200.0), record(3, "part_2", 50.0)];
+
+        assert_eq!(change(&record(4, "part_1", 250.0), &history), Some(25.0));
+        assert_eq!(change(&record(4, "parse", 250.0), &history), None);
+
+        let (_, regressions) = render(&[record(4, "part_1", 250.0), record(4, "part_2", 52.0)], &history, 10.0);
+        assert_eq!(regressions, 1);
+    }
+
+    #[test]
+    fn history_round_trips() {
+        let original = record(7, "parse", 1234.5);
+
+        assert_eq!(Record::from_json(&original.to_json()), Some(original));
+    }
+}
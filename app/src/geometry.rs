@@ -0,0 +1,348 @@
+use std::{fmt, ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign}};
+
+/// An integer type usable as a point coordinate. `Default` is zero.
+pub trait Coord: Copy + Ord + Default + fmt::Debug + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    const ONE: Self;
+
+    /// `|self - other|`, without underflowing unsigned types.
+    fn diff(self, other: Self) -> Self {
+        if self > other { self - other } else { other - self }
+    }
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn saturating_add(self, other: Self) -> Self;
+
+    fn saturating_sub(self, other: Self) -> Self;
+}
+
+macro_rules! coord {
+    ($($t:ty),+) => {
+        $(impl Coord for $t {
+            const ONE: Self = 1;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn saturating_add(self, other: Self) -> Self {
+                <$t>::saturating_add(self, other)
+            }
+
+            fn saturating_sub(self, other: Self) -> Self {
+                <$t>::saturating_sub(self, other)
+            }
+        })+
+    };
+}
+
+coord!(i32, i64, isize, u32, u64, usize);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T
+}
+
+impl<T: Coord> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.diff(other.x) + self.y.diff(other.y)
+    }
+
+    /// The number of king moves between the points.
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x.diff(other.x).max(self.y.diff(other.y))
+    }
+}
+
+impl<T: Coord + Neg<Output = T>> Point2<T> {
+    /// Each coordinate reduced to -1, 0 or 1.
+    pub fn signum(self) -> Self {
+        Point2::new(signum(self.x), signum(self.y))
+    }
+}
+
+fn signum<T: Coord + Neg<Output = T>>(n: T) -> T {
+    match n.cmp(&T::default()) {
+        std::cmp::Ordering::Less => -T::ONE,
+        std::cmp::Ordering::Equal => T::default(),
+        std::cmp::Ordering::Greater => T::ONE
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(p: Point2<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T
+}
+
+impl<T: Coord> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+
+    #[allow(dead_code)]
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.diff(other.x) + self.y.diff(other.y) + self.z.diff(other.z)
+    }
+
+    #[allow(dead_code)]
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x.diff(other.x).max(self.y.diff(other.y)).max(self.z.diff(other.z))
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// The six points sharing a face with this one. Underflows unsigned coordinates at zero.
+    pub fn neighbours6(self) -> [Self; 6] {
+        let Point3 { x, y, z } = self;
+        let one = T::ONE;
+        [
+            Point3::new(x - one, y, z), Point3::new(x + one, y, z),
+            Point3::new(x, y - one, z), Point3::new(x, y + one, z),
+            Point3::new(x, y, z - one), Point3::new(x, y, z + one)
+        ]
+    }
+}
+
+/// Element-wise operators, and scaling by a coordinate.
+macro_rules! ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl<T: Coord> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl<T: Coord> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl<T: Coord> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                $point { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl<T: Coord + Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<T: Coord> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coord> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+ops!(Point2 { x, y });
+ops!(Point3 { x, y, z });
+
+/// The four orthogonal directions, with `y` growing downwards as in the puzzle maps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left
+}
+
+impl Dir4 {
+    /// Clockwise from `Up`.
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub fn turn_right(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn offset<T: Coord + Neg<Output = T>>(self) -> Point2<T> {
+        let (zero, one) = (T::default(), T::ONE);
+        match self {
+            Dir4::Up => Point2::new(zero, -one),
+            Dir4::Right => Point2::new(one, zero),
+            Dir4::Down => Point2::new(zero, one),
+            Dir4::Left => Point2::new(-one, zero)
+        }
+    }
+}
+
+/// The four orthogonal and four diagonal directions, with `y` growing downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW
+}
+
+impl Dir8 {
+    /// Clockwise from `N`.
+    pub const ALL: [Dir8; 8] = [Dir8::N, Dir8::NE, Dir8::E, Dir8::SE, Dir8::S, Dir8::SW, Dir8::W, Dir8::NW];
+
+    /// Turns clockwise by `eighths` of a full turn.
+    pub fn rotate_right(self, eighths: usize) -> Dir8 {
+        Dir8::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// Turns anticlockwise by `eighths` of a full turn.
+    pub fn rotate_left(self, eighths: usize) -> Dir8 {
+        Dir8::ALL[(self as usize + 8 - eighths % 8) % 8]
+    }
+
+    pub fn offset<T: Coord + Neg<Output = T>>(self) -> Point2<T> {
+        let (zero, one) = (T::default(), T::ONE);
+        let (x, y) = match self {
+            Dir8::N => (zero, -one),
+            Dir8::NE => (one, -one),
+            Dir8::E => (one, zero),
+            Dir8::SE => (one, one),
+            Dir8::S => (zero, one),
+            Dir8::SW => (-one, one),
+            Dir8::W => (-one, zero),
+            Dir8::NW => (-one, -one)
+        };
+        Point2::new(x, y)
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+/// The smallest axis-aligned rectangle containing a set of points, bounds inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox<T> {
+    pub min: Point2<T>,
+    pub max: Point2<T>
+}
+
+impl<T: Coord> BoundingBox<T> {
+    /// `None` if there are no points.
+    pub fn from_points(points: impl IntoIterator<Item = Point2<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(BoundingBox { min: first, max: first }, |bounds, p| bounds.including(p)))
+    }
+
+    /// The box grown to contain `p` as well.
+    pub fn including(self, p: Point2<T>) -> Self {
+        BoundingBox {
+            min: Point2::new(self.min.x.min(p.x), self.min.y.min(p.y)),
+            max: Point2::new(self.max.x.max(p.x), self.max.y.max(p.y))
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn contains(&self, p: Point2<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+
+    pub fn area(&self) -> T {
+        self.width() * self.height()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_and_distances() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(-3, 4);
+
+        assert_eq!(a + b, Point2::new(-2, 2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(-a * 2, Point2::new(-2, 4));
+        assert_eq!((b - a).signum(), Point2::new(-1, 1));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(Point2::new(5_usize, 1).manhattan(Point2::new(2, 3)), 5);
+        assert_eq!(Point3::new(1, 2, 3).manhattan(Point3::new(3, 2, 1)), 4);
+        assert_eq!(Point3::new(1, 2, 3).dot(Point3::new(-1, 0, 2)), 5);
+        assert!(Point3::new(1, 2, 3).neighbours6().iter().all(|&p| p.manhattan(Point3::new(1, 2, 3)) == 1));
+    }
+
+    #[test]
+    fn directions_rotate() {
+        assert_eq!(Dir4::Left.turn_right(), Dir4::Up);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Right.reverse(), Dir4::Left);
+        assert_eq!(Dir4::Up.offset::<i32>(), Point2::new(0, -1));
+        assert_eq!(Dir8::N.rotate_left(1), Dir8::NW);
+        assert_eq!(Dir8::NW.rotate_right(3), Dir8::E);
+        assert_eq!(Dir8::from(Dir4::Down), Dir8::S);
+        assert!(Dir4::ALL.iter().all(|&d| Dir8::from(d).offset::<i64>() == d.offset()));
+    }
+
+    #[test]
+    fn bounding_box() {
+        let bounds = BoundingBox::from_points([Point2::new(2, 3), Point2::new(-1, 5), Point2::new(0, 4)]).unwrap();
+
+        assert_eq!(bounds.min, Point2::new(-1, 3));
+        assert_eq!((bounds.width(), bounds.height(), bounds.area()), (4, 3, 12));
+        assert!(bounds.contains(Point2::new(2, 5)));
+        assert!(!bounds.contains(Point2::new(3, 5)));
+        assert_eq!(BoundingBox::<i32>::from_points([]), None);
+    }
+}
@@ -0,0 +1,190 @@
+use std::{fmt, ops::{Index, IndexMut}};
+
+use crate::parse::ParseError;
+
+/// A dense, row-major grid indexed by `(x, y)`, with `(0, 0)` in the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> where T: Clone {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Parses a rectangular map with one cell per character. `cell` returns `None` for characters
+    /// that are not allowed, which is reported as `expected` at that position.
+    pub fn parse(input: &str, expected: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+        let width = input.lines().next()
+            .filter(|l| !l.is_empty())
+            .ok_or_else(|| ParseError::new("a character map").at_line(1))?
+            .chars()
+            .count();
+        let mut cells = vec![];
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let mut columns = 0;
+            for (x, c) in line.chars().enumerate() {
+                if x == width {
+                    return Err(ParseError::new(format!("a row of {} characters", width)).at_line(y + 1).at_column(x + 1));
+                }
+                cells.push(cell(c).ok_or_else(|| ParseError::new(expected).at_line(y + 1).at_column(x + 1))?);
+                columns += 1;
+            }
+            if columns < width {
+                return Err(ParseError::new(format!("a row of {} characters", width)).at_line(y + 1).at_column(columns + 1));
+            }
+            height += 1;
+        }
+
+        Ok(Grid { width, height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The first position, row by row, whose cell matches.
+    pub fn find(&self, mut matches: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter().find(|(_, cell)| matches(cell)).map(|(position, _)| position)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {} out of bounds for width {}", x, self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// The orthogonal neighbours of `(x, y)` that lie inside the grid.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbours(x, y, &[(0, -1), (1, 0), (0, 1), (-1, 0)])
+    }
+
+    /// The orthogonal and diagonal neighbours of `(x, y)` that lie inside the grid.
+    #[allow(dead_code)]
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbours(x, y, &[(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)])
+    }
+
+    fn neighbours(&self, x: usize, y: usize, offsets: &'static [(isize, isize)]) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx).filter(|&x| x < width)?;
+            let y = y.checked_add_signed(dy).filter(|&y| y < height)?;
+            Some((x, y))
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+/// Accepts `(x, y)` tuples as well as `Point2<usize>`.
+impl<T, P: Into<(usize, usize)>> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: P) -> &T {
+        let (x, y) = position.into();
+        self.get(x, y).unwrap_or_else(|| panic!("({}, {}) out of bounds for {}x{} grid", x, y, self.width, self.height))
+    }
+}
+
+impl<T, P: Into<(usize, usize)>> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, position: P) -> &mut T {
+        let (x, y) = position.into();
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y).unwrap_or_else(|| panic!("({}, {}) out of bounds for {}x{} grid", x, y, width, height))
+    }
+}
+
+/// Writes each row on a line of its own, without a trailing newline.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn parses_and_indexes_by_x_then_y() {
+        let grid = digits("123\n456").unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid[(0, 1)], 4);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).rev().collect::<Vec<_>>(), vec![&5, &2]);
+        assert_eq!(grid.find(|&d| d == 5), Some((1, 1)));
+        assert_eq!(grid.to_string(), "123\n456");
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = Grid::new(3, 3, '.');
+
+        assert_eq!(grid.neighbours4(0, 0).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours4(1, 1).count(), 4);
+        assert_eq!(grid.neighbours8(2, 2).collect::<Vec<_>>(), vec![(2, 1), (1, 2), (1, 1)]);
+        assert_eq!(grid.neighbours8(1, 1).count(), 8);
+    }
+
+    #[test]
+    fn reports_ragged_rows_and_bad_characters() {
+        assert_eq!(digits("123\n45").unwrap_err().to_string(), "line 2, column 3, expected a row of 3 characters");
+        assert_eq!(digits("123\n4567").unwrap_err().to_string(), "line 2, column 4, expected a row of 3 characters");
+        assert_eq!(digits("123\n4x6").unwrap_err().to_string(), "line 2, column 2, expected a digit");
+        assert!(digits("").is_err());
+    }
+}
@@ -0,0 +1,128 @@
+use std::{env, error::Error, fmt, fs, io::{self, Read}, path::{Path, PathBuf}};
+
+/// Overrides the inputs root for `--year/--day` lookups.
+pub const ROOT_VAR: &str = "AOC_INPUTS";
+/// Config file searched for in the working directory and its parents.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+    Puzzle { year: u16, day: u8 }
+}
+
+impl Source {
+    /// `-` means stdin, anything else is a path.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            _ => Source::Path(PathBuf::from(arg))
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)
+                    .map_err(|e| InputError::new("<stdin>", e))?;
+                Ok(input)
+            },
+            Source::Path(path) => read_file(path),
+            Source::Puzzle { year, day } => {
+                let root = inputs_root().map_err(|(path, e)| InputError::new(path.display(), e))?;
+                read_file(&puzzle_path(&root, *year, *day))
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub tried: String,
+    pub source: io::Error
+}
+
+impl InputError {
+    fn new(tried: impl fmt::Display, source: io::Error) -> Self {
+        InputError { tried: tried.to_string(), source }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not read input from {}: {}", self.tried, self.source)
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|e| InputError::new(path.display(), e))
+}
+
+pub fn puzzle_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string()).join(format!("day{:02}.txt", day))
+}
+
+/// The inputs root: `$AOC_INPUTS`, else `inputs` from the nearest `aoc.toml`,
+/// else the `inputs` directory next to this crate.
+/// On failure, returns the config file that could not be used.
+pub fn inputs_root() -> Result<PathBuf, (PathBuf, io::Error)> {
+    if let Some(root) = env::var_os(ROOT_VAR) {
+        return Ok(PathBuf::from(root));
+    }
+
+    let cwd = env::current_dir().map_err(|e| (PathBuf::from("."), e))?;
+    if let Some(config) = cwd.ancestors().map(|dir| dir.join(CONFIG_FILE)).find(|path| path.is_file()) {
+        let contents = fs::read_to_string(&config).map_err(|e| (config.clone(), e))?;
+        if let Some(root) = root_from_config(&config, &contents).map_err(|e| (config.clone(), e))? {
+            return Ok(root);
+        }
+    }
+
+    Ok(Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"))
+}
+
+/// Reads the `inputs` key, resolved relative to the directory holding the config file.
+fn root_from_config(config: &Path, contents: &str) -> Result<Option<PathBuf>, io::Error> {
+    let table: toml::Table = contents.parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    match table.get("inputs") {
+        None => Ok(None),
+        Some(toml::Value::String(root)) => Ok(Some(config.parent().unwrap_or(Path::new(".")).join(root))),
+        Some(_) => Err(io::Error::new(io::ErrorKind::InvalidData, "`inputs` should be a string"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn puzzle_path_is_zero_padded() {
+        assert_eq!(puzzle_path(Path::new("inputs"), 2022, 3), Path::new("inputs/2022/day03.txt"));
+    }
+
+    #[test]
+    fn config_root_is_relative_to_config() {
+        let root = root_from_config(Path::new("/repo/aoc.toml"), "inputs = \"app/inputs\"").unwrap();
+
+        assert_eq!(root, Some(PathBuf::from("/repo/app/inputs")));
+        assert_eq!(root_from_config(Path::new("/repo/aoc.toml"), "").unwrap(), None);
+        assert!(root_from_config(Path::new("/repo/aoc.toml"), "inputs = 1").is_err());
+    }
+
+    #[test]
+    fn error_names_the_path() {
+        let error = Source::from_arg("no/such/day99.txt").read().unwrap_err();
+
+        assert!(error.to_string().starts_with("could not read input from no/such/day99.txt: "));
+    }
+}
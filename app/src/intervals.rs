@@ -0,0 +1,192 @@
+use std::ops::RangeInclusive;
+
+use crate::geometry::Coord;
+
+/// A set of integers stored as sorted, disjoint, non-adjacent inclusive intervals, so huge
+/// ranges cost no more than small ones.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    intervals: Vec<(T, T)>
+}
+
+impl<T: Coord> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { intervals: vec![] }
+    }
+
+    /// Adds the range, merging it with every interval it overlaps or touches. Empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // Nothing comes after an interval ending at `T`'s maximum, so it touches everything above it.
+        let first = self.intervals.partition_point(|&(_, e)| e.checked_add(T::ONE).is_some_and(|after| after < start));
+        let last = self.intervals.partition_point(|&(s, _)| end.checked_add(T::ONE).is_none_or(|after| s <= after));
+        if first < last {
+            start = start.min(self.intervals[first].0);
+            end = end.max(self.intervals[last - 1].1);
+        }
+        self.intervals.splice(first..last, [(start, end)]);
+    }
+
+    /// Adds every interval of `other`.
+    #[allow(dead_code)]
+    pub fn merge(&mut self, other: &IntervalSet<T>) {
+        for &(start, end) in &other.intervals {
+            self.insert(start..=end);
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = IntervalSet::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) = (self.intervals.get(i), other.intervals.get(j)) {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                result.intervals.push((start, end));
+            }
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        result
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of integers covered, saturating at `T`'s maximum.
+    pub fn len(&self) -> T {
+        self.intervals.iter().fold(T::default(), |total, &(start, end)| total.saturating_add(end.saturating_sub(start).saturating_add(T::ONE)))
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.contains_range(&(value..=value))
+    }
+
+    /// Whether every value of the range is in the set.
+    pub fn contains_range(&self, range: &RangeInclusive<T>) -> bool {
+        let i = self.intervals.partition_point(|&(_, e)| e < *range.start());
+        self.intervals.get(i).is_some_and(|&(s, e)| s <= *range.start() && *range.end() <= e)
+    }
+
+    /// Whether any value of the range is in the set.
+    pub fn overlaps(&self, range: &RangeInclusive<T>) -> bool {
+        let i = self.intervals.partition_point(|&(_, e)| e < *range.start());
+        self.intervals.get(i).is_some_and(|&(s, _)| s <= *range.end())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// The ranges between consecutive intervals.
+    #[allow(dead_code)]
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals.windows(2).map(|w| w[0].1.saturating_add(T::ONE)..=w[1].0.saturating_sub(T::ONE))
+    }
+
+    /// The values within `bounds` that are not in the set.
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> IntervalSet<T> {
+        let (low, high) = bounds.into_inner();
+        let mut result = IntervalSet::new();
+        let mut next = low;
+
+        for &(start, end) in self.intervals.iter().filter(|&&(s, e)| e >= low && s <= high) {
+            if start > next {
+                result.intervals.push((next, start - T::ONE));
+            }
+            if end >= high {
+                return result;
+            }
+            next = next.max(end + T::ONE);
+        }
+        if next <= high {
+            result.intervals.push((next, high));
+        }
+
+        result
+    }
+}
+
+impl<T: Coord> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Coord> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i64>]) -> IntervalSet<i64> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn insert_merges_overlapping_and_adjacent_ranges() {
+        let intervals = set(&[10..=20, 30..=40, 21..=25, 0..=2, 39..=45, RangeInclusive::new(50, 49)]);
+
+        assert_eq!(intervals.iter().collect::<Vec<_>>(), vec![0..=2, 10..=25, 30..=45]);
+        assert_eq!(intervals.len(), 3 + 16 + 16);
+        assert_eq!(intervals.gaps().collect::<Vec<_>>(), vec![3..=9, 26..=29]);
+
+        let mut merged = intervals.clone();
+        merged.merge(&set(&[3..=9, 26..=29]));
+        assert_eq!(merged, set(&[0..=45]));
+    }
+
+    #[test]
+    fn handles_ranges_up_to_the_maximum() {
+        let mut intervals = set(&[0..=i64::MAX, -5..=-1]);
+        intervals.insert(10..=20);
+
+        assert_eq!(intervals.iter().collect::<Vec<_>>(), vec![-5..=i64::MAX]);
+        assert!(intervals.contains(i64::MAX));
+        assert_eq!(intervals.len(), i64::MAX);
+        assert_eq!(set(&[0..=5, 10..=i64::MAX]).gaps().collect::<Vec<_>>(), vec![6..=9]);
+    }
+
+    #[test]
+    fn queries() {
+        let intervals = set(&[-5..=5, 10..=1_000_000_000_000]);
+
+        assert!(intervals.contains(-5));
+        assert!(!intervals.contains(7));
+        assert!(intervals.contains_range(&(20..=30)));
+        assert!(!intervals.contains_range(&(4..=10)));
+        assert!(intervals.overlaps(&(4..=10)));
+        assert!(!intervals.overlaps(&(6..=9)));
+        assert_eq!(intervals.intersection(&set(&[0..=12])), set(&[0..=5, 10..=12]));
+    }
+
+    #[test]
+    fn complement_within_bounds() {
+        let intervals = set(&[2..=3, 6..=8, 20..=30]);
+
+        assert_eq!(intervals.complement(0..=10), set(&[0..=1, 4..=5, 9..=10]));
+        assert_eq!(intervals.complement(3..=6), set(&[4..=5]));
+        assert!(intervals.complement(21..=25).is_empty());
+        assert_eq!(IntervalSet::new().complement(1..=4), set(&[1..=4]));
+    }
+}
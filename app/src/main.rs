@@ -0,0 +1,452 @@
+mod bench;
+mod geometry;
+mod grid;
+mod input;
+mod intervals;
+mod ocr;
+mod parse;
+mod pool;
+mod registry;
+mod report;
+mod scaffold;
+mod search;
+mod solution;
+mod verify;
+mod viz;
+mod voxels;
+mod y2022;
+
+use std::{collections::{hash_map::Entry, HashMap}, env, path::PathBuf, process, time::Duration};
+
+use input::Source;
+use registry::{Day, Year, YEARS};
+use report::{Failure, Format};
+use solution::Answer;
+use verify::{Answers, Status};
+
+/// Which days to act on. `day: None` means every day of the year, or of every year.
+struct Selection {
+    year: Option<u16>,
+    day: Option<u8>
+}
+
+struct RunOptions {
+    part: Option<u8>,
+    input: Option<Source>,
+    format: Format,
+    jobs: usize,
+    timeout: Option<Duration>,
+    animate: Option<PathBuf>,
+    report: bool
+}
+
+enum Command {
+    Run { selection: Selection, options: RunOptions },
+    Bench { selection: Selection, options: bench::Options },
+    Verify { selection: Selection },
+    List { year: Option<u16> },
+    New { year: u16, day: u8, title: String, force: bool }
+}
+
+fn usage() -> &'static str {
+    "Usage:
+    aoc run [<year>] <day> [--part 1|2] [--input <path>|-] [--format table|json|csv] [--animate <path>] [--report]
+    aoc run --year <year> --day <day> [--part 1|2]
+    aoc run [<year>] --all [--jobs N] [--timeout SECS] [--format table|json|csv]
+    aoc bench [<year>] <day>|--all [--runs N] [--warmup N] [--threshold PCT] [--history <path>] [--no-save]
+    aoc verify [<year>] [<day>]
+    aoc list [<year>]
+    aoc new [<year>] <day> [--title <title>] [--force]
+
+Parse, part 1 and part 2 are timed separately. The table format prints the answers
+followed by a summary of timings, answers and failures; json and csv include the same
+in the records.
+
+--jobs runs up to N days at once (default 1). Each day runs on its own thread, so a
+panic only fails that day, and a day still running after --timeout seconds is reported
+as timed out. Timings are most reliable with a single job.
+
+--animate records the frames a day draws while solving (days 9, 10, 12 and 14) and writes
+them to a .gif animation, or to numbered .png or .ppm files named after the path.
+
+--report prints a breakdown of the parsed input after the answers of a single day, for days that
+have one (day 1 lists every elf, day 12 draws the path), to sanity-check an input.
+
+bench runs every stage --warmup times (default 3), then --runs times (default 20), and
+reports min, median, mean and stddev. Results are appended to the history file
+(default app/bench-history.jsonl) and medians more than --threshold percent (default 10)
+slower than the previous run of the same stage are flagged.
+
+verify checks every answer against app/answers/<year>.toml (or $AOC_ANSWERS/<year>.toml)
+and exits with a non-zero code if any answer differs or a day with answers fails. Days with an
+empty input or no known answers are reported as missing.
+
+new creates src/y<year>/dayNN.rs from templates/day.rs.tmpl, registers it, and adds an
+empty input, an empty example fixture and a placeholder in the answers file. An existing
+module is only replaced with --force. Rebuild to pick up the new day.
+
+The year defaults to the latest one with solutions, except for --all and list,
+which cover every year unless one is given.
+
+Puzzle inputs are read from <root>/<year>/dayNN.txt, where <root> is $AOC_INPUTS,
+the `inputs` key of the nearest aoc.toml, or app/inputs."
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let command = match args.first().map(|s| s.as_str()) {
+        Some("list") => return match args.get(1) {
+            Some(year) => Ok(Command::List { year: Some(parse_year(year)?) }),
+            None => Ok(Command::List { year: None })
+        },
+        Some(command @ ("run" | "bench" | "verify" | "new")) => command,
+        Some(other) => return Err(format!("Unknown command: {}", other)),
+        None => return Err("Missing command".into())
+    };
+
+    let mut day = None;
+    let mut year = None;
+    let mut positional = vec![];
+    let mut all = false;
+    let mut part = None;
+    let mut input = None;
+    let mut format = Format::Table;
+    let mut jobs = 1;
+    let mut timeout = None;
+    let mut animate = None;
+    let mut report = false;
+    let mut options = bench::Options::default();
+    let mut title = None;
+    let mut force = false;
+    let mut iter = args[1..].iter();
+
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().ok_or(format!("{} needs a value", arg));
+        match (command, arg.as_str()) {
+            (_, "--all") => all = true,
+            (_, "--year") => year = Some(parse_year(value()?)?),
+            (_, "--day") => day = Some(parse_day(value()?)?),
+            ("run", "--part") => {
+                let value = value()?;
+                part = match value.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => return Err(format!("Invalid part: {}", value))
+                };
+            },
+            ("run", "--format") => format = value()?.parse()?,
+            ("run", "--input") => input = Some(Source::from_arg(value()?)),
+            ("run", "--animate") => animate = Some(PathBuf::from(value()?)),
+            ("run", "--report") => report = true,
+            ("run", "--jobs") => {
+                jobs = parse_count(arg, value()?)?;
+                if jobs == 0 {
+                    return Err("--jobs must be at least 1".into());
+                }
+            },
+            ("run", "--timeout") => {
+                let value = value()?;
+                timeout = Some(value.parse().ok()
+                    .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                    .ok_or(format!("Invalid timeout: {}", value))?);
+            },
+            ("bench", "--runs") => options.runs = parse_count(arg, value()?)?,
+            ("bench", "--warmup") => options.warmup = parse_count(arg, value()?)?,
+            ("bench", "--threshold") => {
+                let value = value()?;
+                options.threshold = value.parse().map_err(|_| format!("Invalid threshold: {}", value))?;
+            },
+            ("bench", "--history") => options.history = value()?.into(),
+            ("bench", "--no-save") => options.save = false,
+            ("new", "--title") => title = Some(value()?.clone()),
+            ("new", "--force") => force = true,
+            (_, flag) if flag.starts_with("--") => return Err(format!("Unknown option for {}: {}", command, flag)),
+            _ => positional.push(arg)
+        }
+    }
+
+    // `aoc verify` and `aoc verify 2022` check whole years, `aoc verify 13` a single day.
+    if command == "verify" && day.is_none() && (positional.is_empty() || (positional.len() == 1 && positional[0].len() == 4)) {
+        all = true;
+    }
+
+    match (positional.as_slice(), all) {
+        ([], _) => {},
+        ([y], true) | ([y, _], false) if year.is_some() => return Err(format!("Year given twice: {}", y)),
+        ([y], true) => year = Some(parse_year(y)?),
+        ([d], false) => day = Some(parse_day(d)?),
+        ([y, d], false) => {
+            year = Some(parse_year(y)?);
+            day = Some(parse_day(d)?);
+        },
+        (_, _) => return Err(format!("Unexpected argument: {}", positional.last().unwrap()))
+    }
+
+    match (day, all) {
+        (_, true) if command == "new" => return Err("new takes a single day, not --all".into()),
+        (Some(_), true) => return Err("Give either a day or --all, not both".into()),
+        (None, false) => return Err("Missing day".into()),
+        (None, true) if input.is_some() => return Err("--input can only be used with a single day".into()),
+        (None, true) if animate.is_some() => return Err("--animate can only be used with a single day".into()),
+        (None, true) if report => return Err("--report can only be used with a single day".into()),
+        _ => {}
+    }
+    if report && format != Format::Table {
+        return Err("--report can only be used with the table format".into());
+    }
+
+    let selection = Selection { year, day };
+    match command {
+        "run" => Ok(Command::Run { selection, options: RunOptions { part, input, format, jobs, timeout, animate, report } }),
+        "bench" => Ok(Command::Bench { selection, options }),
+        "new" => {
+            let (year, day) = (year.unwrap_or_else(registry::latest_year), day.expect("Should have a day"));
+            let title = title.unwrap_or_else(|| format!("Day {}", day));
+            Ok(Command::New { year, day, title, force })
+        },
+        _ => Ok(Command::Verify { selection })
+    }
+}
+
+fn parse_year(arg: &str) -> Result<u16, String> {
+    arg.parse().map_err(|_| format!("Invalid year: {}", arg))
+}
+
+fn parse_day(arg: &str) -> Result<u8, String> {
+    arg.parse().map_err(|_| format!("Invalid day: {}", arg))
+}
+
+fn parse_count(flag: &str, arg: &str) -> Result<usize, String> {
+    arg.parse().map_err(|_| format!("Invalid value for {}: {}", flag, arg))
+}
+
+fn find_year(year: u16) -> &'static Year {
+    registry::find_year(year).unwrap_or_else(|| {
+        eprintln!("No solutions for {}", year);
+        process::exit(1);
+    })
+}
+
+/// Resolves a selection to `(year, day)` pairs, exiting if the year or day has no solution.
+fn select(selection: &Selection) -> Vec<(u16, &'static Day)> {
+    match selection {
+        Selection { year, day: Some(number) } => {
+            let year = find_year(year.unwrap_or_else(registry::latest_year));
+            match year.find_day(*number) {
+                Some(day) => vec![(year.year, day)],
+                None => {
+                    eprintln!("{} day {} is not implemented", year.year, number);
+                    process::exit(1);
+                }
+            }
+        },
+        Selection { year, day: None } => {
+            let years = year.map_or(YEARS, |y| std::slice::from_ref(find_year(y)));
+            years.iter()
+                .flat_map(|year| year.days.iter().map(move |day| (year.year, day)))
+                .collect()
+        }
+    }
+}
+
+/// Failures are always printed to stderr; answers are printed only for the table format.
+fn run(days: &[(u16, &'static Day)], options: RunOptions) {
+    let RunOptions { part, input, format, jobs, timeout, animate, report: breakdowns } = options;
+    let queue = days.iter()
+        .map(|&(year, day)| pool::Job {
+            year,
+            day,
+            source: input.clone().unwrap_or(Source::Puzzle { year, day: day.number }),
+            parts: part.map_or(vec![1, 2], |p| vec![p]),
+            report: breakdowns
+        })
+        .collect();
+    if animate.is_some() {
+        viz::start();
+    }
+    let reports = pool::run(queue, jobs, timeout);
+    let mut failed = reports.iter().any(|r| r.result.is_err());
+
+    for report in &reports {
+        match &report.result {
+            Err(Failure::Parse(rendered)) => eprintln!("{}", rendered),
+            Err(failure) => eprintln!("{} day {}: {}", report.year, report.day, failure),
+            Ok(solved) if format == Format::Table => {
+                println!("### {} Day {} ###", report.year, report.day);
+                for stage in &solved.parts {
+                    match stage.answer {
+                        Answer::None => {},
+                        _ if stage.answer.is_multi_line() => println!("# Part {}:\n{}", stage.part, stage.answer),
+                        _ => println!("# Part {}: {}", stage.part, stage.answer)
+                    }
+                }
+                match (&solved.report, breakdowns) {
+                    (Some(breakdown), true) => println!("\n{}", breakdown),
+                    (None, true) => eprintln!("{} day {} has no report", report.year, report.day),
+                    _ => {}
+                }
+                println!();
+            },
+            Ok(_) => {}
+        }
+    }
+
+    println!("{}", report::render(format, &reports));
+
+    if let Some(path) = animate {
+        let frames = viz::finish();
+        if frames.is_empty() {
+            eprintln!("No frames were drawn, nothing written to {}", path.display());
+        } else {
+            match viz::write(&path, &frames) {
+                Ok(files) => eprintln!("Wrote {} frame(s) to {}", frames.len(), files[0].display()),
+                Err(e) => {
+                    eprintln!("Could not write {}: {}", path.display(), e);
+                    failed = true;
+                }
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn bench(days: &[(u16, &Day)], options: &bench::Options) {
+    let history = bench::load_history(&options.history).unwrap_or_else(|e| {
+        eprintln!("Could not read bench history from {}: {}", options.history.display(), e);
+        process::exit(1);
+    });
+
+    let mut records = vec![];
+    let mut failed = 0;
+    for &(year, day) in days {
+        let source = Source::Puzzle { year, day: day.number };
+        let result = source.read()
+            .map_err(|e| e.to_string())
+            .and_then(|input| bench::bench_day(year, day.number, day.solve, &input, options)
+                .map_err(|e| e.for_day(day.number).render(&input)));
+        match result {
+            Ok(day_records) => records.extend(day_records),
+            Err(e) => {
+                eprintln!("{} day {}: {}", year, day.number, e);
+                failed += 1;
+            }
+        }
+    }
+
+    let (table, regressions) = bench::render(&records, &history, options.threshold);
+    println!("{}", table);
+    if regressions > 0 {
+        println!("{} stage(s) regressed by more than {}%", regressions, options.threshold);
+    }
+
+    if options.save {
+        if let Err(e) = bench::append_history(&options.history, &records) {
+            eprintln!("Could not write bench history to {}: {}", options.history.display(), e);
+            failed += 1;
+        }
+    }
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+fn verify(days: &[(u16, &Day)]) {
+    let mut answers: HashMap<u16, Answers> = HashMap::new();
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+
+    for &(year, day) in days {
+        if let Entry::Vacant(entry) = answers.entry(year) {
+            entry.insert(Answers::load(&verify::answers_path(year)).unwrap_or_else(|e| {
+                eprintln!("Could not read answers: {}", e);
+                process::exit(1);
+            }));
+        }
+
+        let input = Source::Puzzle { year, day: day.number }.read().map_err(|e| e.to_string());
+        // A day without a puzzle input or known answers has nothing to check yet.
+        let unchecked = match &input {
+            Ok(input) if input.trim().is_empty() => Some("no puzzle input"),
+            _ if !answers[&year].has_day(day.number) => Some("no known answers"),
+            _ => None
+        };
+        if let Some(reason) = unchecked {
+            println!("{} day {:>2}         missing  {}", year, day.number, reason);
+            *counts.entry("missing").or_default() += 1;
+            continue;
+        }
+
+        let solved = input
+            .and_then(|input| (day.solve)(&input, &[1, 2], false).map_err(|e| e.for_day(day.number).render(&input)));
+        let solved = match solved {
+            Ok(solved) => solved,
+            Err(e) => {
+                println!("{} day {:>2}         error  {}", year, day.number, e);
+                *counts.entry("error").or_default() += 1;
+                continue;
+            }
+        };
+
+        for stage in &solved.parts {
+            let status = answers[&year].check(day.number, stage.part, &stage.answer);
+            match (&status, &stage.answer) {
+                (Status::Missing, Answer::None) => continue,
+                (Status::Fail { expected }, answer) if stage.answer.is_multi_line() || expected.contains('\n') =>
+                    println!("{} day {:>2} part {}  {}\nexpected:\n{}\ngot:\n{}", year, day.number, stage.part, status, expected, answer),
+                (Status::Fail { expected }, answer) =>
+                    println!("{} day {:>2} part {}  {}  expected {}, got {}", year, day.number, stage.part, status, expected, answer),
+                _ => println!("{} day {:>2} part {}  {}", year, day.number, stage.part, status)
+            }
+            *counts.entry(match status {
+                Status::Pass => "pass",
+                Status::Fail { .. } => "fail",
+                Status::Missing => "missing"
+            }).or_default() += 1;
+        }
+    }
+
+    let count = |status| counts.get(status).copied().unwrap_or(0);
+    println!("\npass: {}, fail: {}, missing: {}, error: {}", count("pass"), count("fail"), count("missing"), count("error"));
+    if count("fail") + count("error") > 0 {
+        process::exit(1);
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, usage());
+            process::exit(2);
+        }
+    };
+
+    match command {
+        Command::List { year } => {
+            let years = year.map_or(YEARS, |y| std::slice::from_ref(find_year(y)));
+            for year in years {
+                println!("{}", year.year);
+                for day in year.days {
+                    println!("  {:>2}  {}", day.number, day.title);
+                }
+            }
+        },
+        Command::Run { selection, options } => run(&select(&selection), options),
+        Command::Bench { selection, options } => bench(&select(&selection), &options),
+        Command::Verify { selection } => verify(&select(&selection)),
+        Command::New { year, day, title, force } => match scaffold::new_day(year, day, &title, force) {
+            Ok(changed) => {
+                for path in changed {
+                    println!("{}", path.display());
+                }
+                eprintln!("Added {} day {}; rebuild to run it.", year, day);
+            },
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+/// Width of a glyph plus the blank column after it.
+const CELL: usize = 5;
+const HEIGHT: usize = 6;
+
+/// The letters of the 4x6 font used by the puzzles, one row per line.
+const FONT: &[(char, [&str; HEIGHT])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...", "#...", ".#.#", "..#.", "..#.", "..#."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"])
+];
+
+/// Reads a render of `#` and `.` rows, six high, as letters. `None` if any glyph is not in the font.
+pub fn read(render: &str) -> Option<String> {
+    let rows: Vec<Vec<char>> = render.lines().map(|l| l.chars().collect()).collect();
+    let width = rows.first()?.len();
+    if rows.len() != HEIGHT || rows.iter().any(|r| r.len() != width) {
+        return None;
+    }
+
+    (0..width.div_ceil(CELL))
+        .map(|i| {
+            let glyph: Vec<String> = rows.iter()
+                .map(|row| row[i * CELL..(i * CELL + 4).min(width)].iter().collect())
+                .collect();
+            FONT.iter()
+                .find(|(_, pattern)| pattern.iter().zip(&glyph).all(|(p, g)| p == g))
+                .map(|&(letter, _)| letter)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_known_letters() {
+        let render = "\
+###..####.#..#.
+#..#.#....#..#.
+#..#.###..####.
+###..#....#..#.
+#.#..#....#..#.
+#..#.####.#..#.";
+
+        assert_eq!(read(render), Some("REH".into()));
+    }
+
+    #[test]
+    fn rejects_unknown_glyphs() {
+        assert_eq!(read("####\n####\n####\n####\n####\n####"), None);
+        assert_eq!(read("###.\n#..#"), None);
+        assert_eq!(read(""), None);
+    }
+}
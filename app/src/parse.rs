@@ -0,0 +1,143 @@
+use std::{error::Error, fmt, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub expected: String
+}
+
+impl ParseError {
+    pub fn new(expected: impl Into<String>) -> Self {
+        ParseError { day: None, line: None, column: None, expected: expected.into() }
+    }
+
+    /// Points the error at `token`, which must be a slice of `line`.
+    pub fn at(line: &str, token: &str, expected: impl Into<String>) -> Self {
+        ParseError::new(expected).at_column(column_of(line, token))
+    }
+
+    /// Points the error just past the end of `line`, for tokens that are missing.
+    pub fn at_end(line: &str, expected: impl Into<String>) -> Self {
+        ParseError::new(expected).at_column(line.len() + 1)
+    }
+
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    /// Sets the line number unless a more specific parser already did.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Shifts a line number relative to a block of lines to one relative to the whole input.
+    pub fn offset(mut self, lines: usize) -> Self {
+        self.line = self.line.map(|l| l + lines);
+        self
+    }
+
+    pub fn for_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    /// Formats the error together with the offending line of `input` and a marker under the column.
+    pub fn render(&self, input: &str) -> String {
+        let mut output = format!("error: {}", self);
+
+        if let Some(source) = self.line.and_then(|l| input.lines().nth(l - 1)) {
+            let number = self.line.unwrap().to_string();
+            let gutter = " ".repeat(number.len());
+            output.push_str(&format!("\n {} | {}", number, source));
+            if let Some(column) = self.column {
+                output.push_str(&format!("\n {} | {}^", gutter, " ".repeat(column - 1)));
+            }
+        }
+
+        output
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location = [
+            self.day.map(|d| format!("day {}", d)),
+            self.line.map(|l| format!("line {}", l)),
+            self.column.map(|c| format!("column {}", c))
+        ];
+
+        for part in location.iter().flatten() {
+            write!(f, "{}, ", part)?;
+        }
+
+        write!(f, "expected {}", self.expected)
+    }
+}
+
+impl Error for ParseError {}
+
+/// 1-based column of `token` within `line`. `token` must be a slice of `line`.
+pub fn column_of(line: &str, token: &str) -> usize {
+    (token.as_ptr() as usize).saturating_sub(line.as_ptr() as usize) + 1
+}
+
+/// Returns `parts[index]`, or an error pointing just past the last part if the token is missing.
+/// `parts` must be slices of `line`.
+pub fn token<'a>(line: &str, parts: &[&'a str], index: usize, expected: &str) -> Result<&'a str, ParseError> {
+    parts.get(index).copied()
+        .filter(|t| !t.is_empty())
+        .ok_or_else(|| match parts.last() {
+            Some(last) => ParseError::new(expected).at_column(column_of(line, last) + last.len()),
+            None => ParseError::at_end(line, expected)
+        })
+}
+
+pub fn number<T: FromStr>(line: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| ParseError::at(line, token, expected))
+}
+
+/// Parses every line of `input` with `f`, attaching the 1-based line number to any error.
+pub fn lines<'a, T>(input: &'a str, mut f: impl FnMut(&'a str) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    input.lines()
+        .enumerate()
+        .map(|(i, l)| f(l).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_token() {
+        let line = "move 1 from x to 3";
+        let parts: Vec<&str> = line.split(' ').collect();
+        let error = number::<u8>(line, parts[3], "a stack number").unwrap_err();
+
+        assert_eq!(error.column, Some(13));
+        assert_eq!(error.to_string(), "column 13, expected a stack number");
+    }
+
+    #[test]
+    fn missing_token_points_past_end() {
+        let line = "move 1 from 2";
+        let parts: Vec<&str> = line.split(' ').collect();
+        let error = token(line, &parts, 5, "a stack number").unwrap_err();
+
+        assert_eq!(error.column, Some(14));
+    }
+
+    #[test]
+    fn lines_keeps_innermost_line() {
+        let error = lines("1\n2\nx", |l| number::<u32>(l, l, "a number"))
+            .unwrap_err()
+            .for_day(1);
+
+        assert_eq!(error.to_string(), "day 1, line 3, column 1, expected a number");
+        assert_eq!(error.render("1\n2\nx"), "error: day 1, line 3, column 1, expected a number\n 3 | x\n   | ^");
+    }
+}
@@ -0,0 +1,121 @@
+use std::{any::Any, panic::{self, AssertUnwindSafe}, sync::{mpsc, Mutex}, thread, time::Duration};
+
+use crate::{input::Source, registry::Day, report::{DayReport, Failure}};
+
+pub struct Job {
+    pub year: u16,
+    pub day: &'static Day,
+    pub source: Source,
+    pub parts: Vec<u8>,
+    /// Whether to build the day's report as well as its answers.
+    pub report: bool
+}
+
+/// Runs the jobs on `workers` threads and returns a report per job, in the order of `jobs`.
+///
+/// Every day runs on a thread of its own so a panic only fails that day. A day that is still
+/// running after `timeout` is reported as timed out and left behind; it stops when the process exits.
+pub fn run(jobs: Vec<Job>, workers: usize, timeout: Option<Duration>) -> Vec<DayReport> {
+    let count = jobs.len();
+    let queue = Mutex::new(jobs.into_iter().enumerate());
+    let (results, received) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..workers.clamp(1, count.max(1)) {
+            let results = results.clone();
+            let queue = &queue;
+            scope.spawn(move || loop {
+                let next = queue.lock().expect("Should not be poisoned").next();
+                let Some((index, job)) = next else { break };
+                results.send((index, run_job(job, timeout))).expect("Should still be collecting results");
+            });
+        }
+    });
+    drop(results);
+
+    let mut reports: Vec<(usize, DayReport)> = received.iter().collect();
+    reports.sort_by_key(|(index, _)| *index);
+    reports.into_iter().map(|(_, report)| report).collect()
+}
+
+fn run_job(job: Job, timeout: Option<Duration>) -> DayReport {
+    let (year, number, solve) = (job.year, job.day.number, job.day.solve);
+    let (sender, receiver) = mpsc::channel();
+
+    let spawned = thread::Builder::new()
+        .name(format!("{} day {}", year, number))
+        .spawn(move || {
+            let result = job.source.read()
+                .map_err(|e| Failure::Input(e.to_string()))
+                .and_then(|input| match panic::catch_unwind(AssertUnwindSafe(|| solve(&input, &job.parts, job.report))) {
+                    Ok(Ok(solved)) => Ok(solved),
+                    Ok(Err(e)) => Err(Failure::Parse(e.for_day(number).render(&input))),
+                    Err(payload) => Err(Failure::Panic(panic_message(payload)))
+                });
+            // The receiver is gone if the day timed out.
+            let _ = sender.send(result);
+        });
+
+    let result = match (spawned, timeout) {
+        (Err(e), _) => Err(Failure::Panic(format!("could not start thread: {}", e))),
+        (Ok(_), Some(timeout)) => receiver.recv_timeout(timeout).unwrap_or(Err(Failure::Timeout(timeout))),
+        (Ok(_), None) => receiver.recv().expect("Should send a result unless the thread was killed")
+    };
+
+    DayReport { year, day: number, result }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().map_or("panicked".to_string(), |s| s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse::ParseError, solution::{Answer, Solved, Stage}};
+
+    fn answer(_: &str, _: &[u8], _: bool) -> Result<Solved, ParseError> {
+        Ok(Solved { parse: Duration::ZERO, parts: vec![Stage { part: 1, answer: Answer::Int(42), elapsed: Duration::ZERO }], report: None })
+    }
+
+    fn panics(_: &str, _: &[u8], _: bool) -> Result<Solved, ParseError> {
+        panic!("not yet implemented")
+    }
+
+    fn hangs(_: &str, _: &[u8], _: bool) -> Result<Solved, ParseError> {
+        thread::sleep(Duration::from_secs(60));
+        unreachable!()
+    }
+
+    static DAYS: [Day; 3] = [
+        Day { number: 1, title: "Answers", solve: answer },
+        Day { number: 2, title: "Panics", solve: panics },
+        Day { number: 3, title: "Hangs", solve: hangs }
+    ];
+
+    fn job(day: &'static Day) -> Job {
+        Job { year: 2000, day, source: Source::Path(file!().into()), parts: vec![1, 2], report: false }
+    }
+
+    #[test]
+    fn isolates_failures_and_keeps_order() {
+        let jobs = vec![job(&DAYS[2]), job(&DAYS[1]), job(&DAYS[0]), job(&DAYS[0])];
+        let reports = run(jobs, 2, Some(Duration::from_millis(200)));
+
+        assert_eq!(reports.iter().map(|r| r.day).collect::<Vec<_>>(), vec![3, 2, 1, 1]);
+        assert!(matches!(reports[0].result, Err(Failure::Timeout(_))));
+        assert!(matches!(&reports[1].result, Err(Failure::Panic(message)) if message == "not yet implemented"));
+        assert!(reports[2..].iter().all(|r| r.result.is_ok()));
+    }
+
+    #[test]
+    fn reports_unreadable_input() {
+        let mut missing = job(&DAYS[0]);
+        missing.source = Source::Path("does/not/exist.txt".into());
+
+        assert!(matches!(run(vec![missing], 4, None)[0].result, Err(Failure::Input(_))));
+    }
+}
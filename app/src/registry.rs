@@ -0,0 +1,45 @@
+use crate::solution::Solver;
+
+pub struct Day {
+    pub number: u8,
+    pub title: &'static str,
+    pub solve: Solver
+}
+
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day]
+}
+
+/// Every year with solutions, oldest first.
+pub const YEARS: &[Year] = &[
+    Year { year: 2022, days: crate::y2022::DAYS },
+];
+
+pub fn latest_year() -> u16 {
+    YEARS.last().expect("Should be at least one year").year
+}
+
+pub fn find_year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+impl Year {
+    pub fn find_day(&self, number: u8) -> Option<&'static Day> {
+        self.days.iter().find(|d| d.number == number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn years_and_days_are_sorted_and_unique() {
+        assert!(YEARS.windows(2).all(|w| w[0].year < w[1].year));
+        for year in YEARS {
+            assert!(year.days.windows(2).all(|w| w[0].number < w[1].number), "{}", year.year);
+            assert!(year.days.iter().all(|d| (1..=25).contains(&d.number)), "{}", year.year);
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use std::{fmt::{self, Write}, str::FromStr, time::Duration};
+
+use serde_json::{json, Value};
+
+use crate::solution::{Answer, Solved, Stage};
+
+#[cfg(debug_assertions)]
+pub fn get_env() -> &'static str {
+    "DEBUG"
+}
+
+#[cfg(not(debug_assertions))]
+pub fn get_env() -> &'static str {
+    "RELEASE"
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+    Csv
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Invalid format: {} (expected table, json or csv)", s))
+        }
+    }
+}
+
+/// Why a day produced no answers.
+#[derive(Debug)]
+pub enum Failure {
+    Input(String),
+    /// The rendered parse error, pointing into the input.
+    Parse(String),
+    Panic(String),
+    Timeout(Duration)
+}
+
+impl Failure {
+    fn kind(&self) -> &'static str {
+        match self {
+            Failure::Input(_) => "input",
+            Failure::Parse(_) => "parse",
+            Failure::Panic(_) => "panic",
+            Failure::Timeout(_) => "timeout"
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Input(message) | Failure::Parse(message) => write!(f, "{}", message),
+            Failure::Panic(message) => write!(f, "panicked: {}", message),
+            Failure::Timeout(timeout) => write!(f, "timed out after {:?}", timeout)
+        }
+    }
+}
+
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub result: Result<Solved, Failure>
+}
+
+impl DayReport {
+    fn part(&self, part: u8) -> Option<&Stage> {
+        self.result.as_ref().ok()?.parts.iter().find(|s| s.part == part)
+    }
+}
+
+/// Renders the timings and answers of every stage, and the failure of every day that has no answers.
+pub fn render(format: Format, reports: &[DayReport]) -> String {
+    match format {
+        Format::Table => table(reports),
+        Format::Json => json(reports),
+        Format::Csv => csv(reports)
+    }
+}
+
+/// Multi-line answers are summarised; a failed day shows the first line of its failure instead of answers.
+fn table(reports: &[DayReport]) -> String {
+    let nanos = |d: Option<Duration>| d.map_or("-".to_string(), |d| d.as_nanos().to_string());
+    let answer = |stage: Option<&Stage>| match stage.map(|s| &s.answer) {
+        None | Some(Answer::None) => "-".to_string(),
+        Some(answer) if answer.is_multi_line() => format!("({} lines)", answer.to_string().lines().count()),
+        Some(answer) => answer.to_string()
+    };
+
+    let mut output = format!("{:>4}  {:>3}  {:<7}  {:>12}  {:>12}  {:>12}  {:>12}  {:<20}  {}\n",
+        "year", "day", "status", "parse (ns)", "part 1 (ns)", "part 2 (ns)", "total (ns)", "part 1", "part 2");
+    for report in reports {
+        let (parse, total) = match &report.result {
+            Ok(solved) => (Some(solved.parse), Some(solved.total())),
+            Err(_) => (None, None)
+        };
+        let (status, answers) = match &report.result {
+            Ok(_) => ("ok", format!("{:<20}  {}", answer(report.part(1)), answer(report.part(2)))),
+            Err(failure) => (failure.kind(), failure.to_string().lines().next().unwrap_or_default().to_string())
+        };
+        writeln!(output, "{:>4}  {:>3}  {:<7}  {:>12}  {:>12}  {:>12}  {:>12}  {}",
+            report.year,
+            report.day,
+            status,
+            nanos(parse),
+            nanos(report.part(1).map(|s| s.elapsed)),
+            nanos(report.part(2).map(|s| s.elapsed)),
+            nanos(total),
+            answers).unwrap();
+    }
+    let failed = reports.iter().filter(|r| r.result.is_err()).count();
+    write!(output, "days: {}, failed: {}, profile: {}", reports.len(), failed, get_env()).unwrap();
+
+    output
+}
+
+fn answer_value(answer: &Answer) -> Value {
+    match answer {
+        Answer::Int(n) => json!(n),
+        Answer::Text(s) | Answer::Render(s) => json!(s),
+        Answer::None => Value::Null
+    }
+}
+
+fn json(reports: &[DayReport]) -> String {
+    let days = reports.iter()
+        .map(|report| match &report.result {
+            Ok(solved) => json!({
+                "year": report.year,
+                "day": report.day,
+                "status": "ok",
+                "parse_ns": solved.parse.as_nanos() as u64,
+                "total_ns": solved.total().as_nanos() as u64,
+                "parts": solved.parts.iter()
+                    .map(|stage| json!({
+                        "part": stage.part,
+                        "answer": answer_value(&stage.answer),
+                        "ns": stage.elapsed.as_nanos() as u64
+                    }))
+                    .collect::<Vec<Value>>()
+            }),
+            Err(failure) => json!({
+                "year": report.year,
+                "day": report.day,
+                "status": failure.kind(),
+                "error": failure.to_string()
+            })
+        })
+        .collect::<Vec<Value>>();
+
+    json!({ "profile": get_env(), "days": days }).to_string()
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains(['"', ',', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn csv(reports: &[DayReport]) -> String {
+    let mut output = String::from("year,day,stage,ns,answer,profile");
+    for report in reports {
+        let solved = match &report.result {
+            Ok(solved) => solved,
+            Err(failure) => {
+                write!(output, "\n{},{},{},,{},{}", report.year, report.day, failure.kind(), csv_field(&failure.to_string()), get_env()).unwrap();
+                continue;
+            }
+        };
+        write!(output, "\n{},{},parse,{},,{}", report.year, report.day, solved.parse.as_nanos(), get_env()).unwrap();
+        for stage in &solved.parts {
+            let answer = match stage.answer {
+                Answer::None => String::new(),
+                _ => csv_field(&stage.answer.to_string())
+            };
+            write!(output, "\n{},{},part_{},{},{},{}",
+                report.year, report.day, stage.part, stage.elapsed.as_nanos(), answer, get_env()).unwrap();
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reports() -> Vec<DayReport> {
+        vec![
+            DayReport {
+                year: 2022,
+                day: 10,
+                result: Ok(Solved {
+                    parse: Duration::from_nanos(1500),
+                    parts: vec![
+                        Stage { part: 1, answer: Answer::Int(13140), elapsed: Duration::from_nanos(20) },
+                        Stage { part: 2, answer: Answer::Render("#.\n.#".into()), elapsed: Duration::from_nanos(300) }
+                    ],
+                    report: None
+                })
+            },
+            DayReport { year: 2022, day: 16, result: Err(Failure::Panic("not yet implemented".into())) }
+        ]
+    }
+
+    #[test]
+    fn table_has_a_column_per_stage() {
+        let output = render(Format::Table, &reports());
+        let rows: Vec<Vec<&str>> = output.lines().map(|line| line.split_whitespace().collect()).collect();
+
+        assert_eq!(rows[1], vec!["2022", "10", "ok", "1500", "20", "300", "1820", "13140", "(2", "lines)"]);
+        assert_eq!(rows[2], vec!["2022", "16", "panic", "-", "-", "-", "-", "panicked:", "not", "yet", "implemented"]);
+        assert!(output.ends_with(&format!("days: 2, failed: 1, profile: {}", get_env())));
+    }
+
+    #[test]
+    fn json_keeps_nanoseconds_and_answers() {
+        let value: Value = serde_json::from_str(&render(Format::Json, &reports())).unwrap();
+
+        assert_eq!(value["days"][0]["parse_ns"], 1500);
+        assert_eq!(value["days"][0]["parts"][0]["answer"], 13140);
+        assert_eq!(value["days"][0]["parts"][1]["ns"], 300);
+        assert_eq!(value["days"][1]["status"], "panic");
+    }
+
+    #[test]
+    fn csv_quotes_multi_line_answers() {
+        let output = render(Format::Csv, &reports());
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines[1], format!("2022,10,parse,1500,,{}", get_env()));
+        assert_eq!(lines[3], "2022,10,part_2,300,\"#.");
+        assert_eq!(lines[5], format!("2022,16,panic,,panicked: not yet implemented,{}", get_env()));
+    }
+}
@@ -0,0 +1,193 @@
+use std::{fs, path::{Path, PathBuf}};
+
+use crate::{input, verify};
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs.tmpl");
+const YEAR_TEMPLATE: &str = include_str!("../templates/year.rs.tmpl");
+
+/// Creates and registers the module for a new day, along with an empty puzzle input, an empty
+/// example fixture and an answers placeholder. Files that already exist are left alone, except
+/// the module itself, which is only replaced with `force`. Returns the files created or changed.
+pub fn new_day(year: u16, day: u8, title: &str, force: bool) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Invalid day: {} (expected 1 to 25)", day));
+    }
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let source = root.join("src");
+    let year_dir = source.join(format!("y{}", year));
+    let module = year_dir.join(format!("day{:02}.rs", day));
+    let mut changed = vec![];
+
+    if !year_dir.exists() {
+        write(&year_dir.join("mod.rs"), YEAR_TEMPLATE)?;
+        edit(&source.join("main.rs"), |main| add_year_module(main, year))?;
+        edit(&source.join("registry.rs"), |registry| add_year(registry, year))?;
+        changed.extend([source.join("main.rs"), source.join("registry.rs")]);
+    }
+
+    if module.exists() && !force {
+        return Err(format!("{} already exists (use --force to replace it)", module.display()));
+    }
+    let year_module = year_dir.join("mod.rs");
+    let registered = read(&year_module)?;
+    if !registered.lines().any(|l| l == format!("mod day{:02};", day)) {
+        write(&year_module, &add_day(&registered, day, title)?)?;
+        changed.push(year_module);
+    }
+    write(&module, &day_module(year, day))?;
+    changed.push(module);
+
+    let inputs = input::inputs_root().map_err(|(path, e)| format!("Could not read {}: {}", path.display(), e))?;
+    let files = [
+        input::puzzle_path(&inputs, year, day),
+        root.join("examples").join(year.to_string()).join(format!("day{:02}-1.txt", day))
+    ];
+    for file in files.into_iter().filter(|f| !f.exists()) {
+        write(&file, "")?;
+        changed.push(file);
+    }
+
+    let answers = verify::answers_path(year);
+    let existing = if answers.exists() { read(&answers)? } else { String::new() };
+    if !existing.lines().any(|l| l.trim() == format!("[day{:02}]", day)) {
+        write(&answers, &add_answers(&existing, day))?;
+        changed.push(answers);
+    }
+
+    Ok(changed)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Could not read {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    path.parent().map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(path, contents))
+        .map_err(|e| format!("Could not write {}: {}", path.display(), e))
+}
+
+fn edit(path: &Path, change: impl FnOnce(&str) -> Result<String, String>) -> Result<(), String> {
+    write(path, &change(&read(path)?)?)
+}
+
+fn day_module(year: u16, day: u8) -> String {
+    DAY_TEMPLATE.replace("{{day}}", &format!("{:02}", day)).replace("{{year}}", &year.to_string())
+}
+
+/// Inserts `line` before the first line that sorts after it among those `key` recognises,
+/// or after the last of them, or before `fallback` if there are none.
+fn insert_sorted(source: &str, line: String, key: impl Fn(&str) -> Option<u16>, fallback: &str) -> Result<String, String> {
+    let new_key = key(&line).expect("Should recognise the inserted line");
+    let mut lines: Vec<&str> = source.lines().collect();
+    let keyed: Vec<(usize, u16)> = lines.iter().enumerate().filter_map(|(i, l)| Some((i, key(l)?))).collect();
+
+    let index = match keyed.iter().find(|&&(_, k)| k > new_key) {
+        Some(&(i, _)) => i,
+        None => match keyed.last() {
+            Some(&(i, _)) => i + 1,
+            None => lines.iter().position(|l| l.trim_start().starts_with(fallback))
+                .ok_or_else(|| format!("expected a line starting with {}", fallback))?
+        }
+    };
+    lines.insert(index, &line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn number_after(line: &str, prefix: &str, terminator: char) -> Option<u16> {
+    line.trim_start().strip_prefix(prefix)?.split(terminator).next()?.trim().parse().ok()
+}
+
+/// Adds the `mod` declaration and the `DAYS` entry to a year module.
+fn add_day(module: &str, day: u8, title: &str) -> Result<String, String> {
+    let first = !module.lines().any(|l| number_after(l, "mod day", ';').is_some());
+    let mut module = insert_sorted(module, format!("mod day{:02};", day), |l| number_after(l, "mod day", ';'), "use ")?;
+    if first {
+        // The first `mod` of a new year goes above the imports, separated by a blank line.
+        module = module.replacen(";\nuse ", ";\n\nuse ", 1);
+    }
+    let entry = format!("    Day {{ number: {}, title: {:?}, solve: solve::<day{:02}::Day{:02}> }},", day, title, day, day);
+
+    insert_sorted(&module, entry, |l| number_after(l, "Day { number:", ','), "];")
+}
+
+fn add_year_module(main: &str, year: u16) -> Result<String, String> {
+    insert_sorted(main, format!("mod y{};", year), |l| number_after(l, "mod y", ';'), "use ")
+}
+
+fn add_year(registry: &str, year: u16) -> Result<String, String> {
+    let entry = format!("    Year {{ year: {}, days: crate::y{}::DAYS }},", year, year);
+    insert_sorted(registry, entry, |l| number_after(l, "Year { year:", ','), "];")
+}
+
+/// Adds a commented-out table for the day before the first later day, or at the end.
+fn add_answers(answers: &str, day: u8) -> String {
+    let table = format!("[day{:02}]\n# part1 = \n# part2 = \n", day);
+    let later = answers.match_indices("[day")
+        .find(|&(i, _)| (i == 0 || answers[..i].ends_with('\n')) && number_after(&answers[i..], "[day", ']').is_some_and(|d| d > day as u16));
+    if let Some((i, _)) = later {
+        return format!("{}{}\n{}", &answers[..i], table, &answers[i..]);
+    }
+
+    let separator = if answers.is_empty() || answers.ends_with("\n\n") { "" } else if answers.ends_with('\n') { "\n" } else { "\n\n" };
+    format!("{}{}{}", answers, separator, table)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODULE: &str = "mod day01;
+mod day03;
+
+use crate::{registry::Day, solution::solve};
+
+pub const DAYS: &[Day] = &[
+    Day { number: 1, title: \"One\", solve: solve::<day01::Day01> },
+    Day { number: 3, title: \"Three\", solve: solve::<day03::Day03> },
+];
+";
+
+    #[test]
+    fn registers_days_in_order() {
+        let module = add_day(MODULE, 2, "Two \"quoted\"").unwrap();
+        let lines: Vec<&str> = module.lines().collect();
+
+        assert_eq!(lines[1], "mod day02;");
+        assert_eq!(lines[8], "    Day { number: 2, title: \"Two \\\"quoted\\\"\", solve: solve::<day02::Day02> },");
+
+        let module = add_day(MODULE, 25, "Last").unwrap();
+        assert!(module.contains("mod day03;\nmod day25;\n"));
+        assert!(module.ends_with("solve: solve::<day25::Day25> },\n];\n"));
+    }
+
+    #[test]
+    fn registers_the_first_day_of_a_year() {
+        let module = add_day(YEAR_TEMPLATE, 7, "Seven").unwrap();
+
+        assert!(module.starts_with("mod day07;\n\nuse crate::"));
+        assert!(module.ends_with("&[\n    Day { number: 7, title: \"Seven\", solve: solve::<day07::Day07> },\n];\n"));
+    }
+
+    #[test]
+    fn registers_years() {
+        let registry = "pub const YEARS: &[Year] = &[\n    Year { year: 2022, days: crate::y2022::DAYS },\n];\n";
+
+        assert!(add_year(registry, 2021).unwrap().contains("&[\n    Year { year: 2021, days: crate::y2021::DAYS },\n    Year { year: 2022"));
+        assert!(add_year_module("mod verify;\nmod y2022;\n\nuse std::env;\n", 2023).unwrap().contains("mod y2022;\nmod y2023;\n\n"));
+    }
+
+    #[test]
+    fn fills_in_templates() {
+        let module = day_module(2022, 9);
+
+        assert!(module.contains("pub struct Day09;"));
+        assert!(module.contains("Day09 in 2022;"));
+        assert!(!module.contains("{{"));
+        assert_eq!(add_answers("[day01]\npart1 = 1\n", 2), "[day01]\npart1 = 1\n\n[day02]\n# part1 = \n# part2 = \n");
+        assert_eq!(add_answers("[day01]\npart1 = 1\n\n[day03]\npart1 = 3\n", 2),
+            "[day01]\npart1 = 1\n\n[day02]\n# part1 = \n# part2 = \n\n[day03]\npart1 = 3\n");
+    }
+}
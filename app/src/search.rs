@@ -0,0 +1,208 @@
+use std::{cmp::Ordering, collections::{BinaryHeap, HashMap, HashSet, VecDeque}, hash::Hash, ops::Add};
+
+/// The outcome of a search that reached a goal.
+#[derive(Debug, Clone)]
+pub struct Found<N, C> {
+    /// Number of steps for BFS, total edge cost for Dijkstra and A*.
+    pub cost: C,
+    /// From the start it came from to the goal, both included.
+    pub path: Vec<N>,
+    /// Every node reached before the goal was found.
+    #[allow(dead_code)]
+    pub visited: HashSet<N>
+}
+
+/// Breadth-first search over unweighted edges.
+pub fn bfs<N, I>(start: N, neighbours: impl FnMut(&N) -> I, is_goal: impl FnMut(&N) -> bool) -> Option<Found<N, usize>>
+where N: Clone + Eq + Hash, I: IntoIterator<Item = N> {
+    bfs_multi([start], neighbours, is_goal)
+}
+
+/// Breadth-first search from all `starts` at once, finding the goal nearest to any of them.
+pub fn bfs_multi<N, I>(starts: impl IntoIterator<Item = N>, mut neighbours: impl FnMut(&N) -> I, mut is_goal: impl FnMut(&N) -> bool) -> Option<Found<N, usize>>
+where N: Clone + Eq + Hash, I: IntoIterator<Item = N> {
+    let mut parents: HashMap<N, Option<N>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !parents.contains_key(&start) {
+            parents.insert(start.clone(), None);
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, steps)) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(found(node, steps, parents));
+        }
+
+        for next in neighbours(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(node.clone()));
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// Every node reachable from `starts`, the starts included.
+pub fn flood_fill<N, I>(starts: impl IntoIterator<Item = N>, mut neighbours: impl FnMut(&N) -> I) -> HashSet<N>
+where N: Clone + Eq + Hash, I: IntoIterator<Item = N> {
+    let mut reached = HashSet::new();
+    let mut stack = vec![];
+    for start in starts {
+        if reached.insert(start.clone()) {
+            stack.push(start);
+        }
+    }
+
+    while let Some(node) = stack.pop() {
+        for next in neighbours(&node) {
+            if reached.insert(next.clone()) {
+                stack.push(next);
+            }
+        }
+    }
+
+    reached
+}
+
+/// Lowest-cost search over weighted edges. `neighbours` yields `(node, edge cost)` pairs.
+#[allow(dead_code)]
+pub fn dijkstra<N, C, I>(start: N, neighbours: impl FnMut(&N) -> I, is_goal: impl FnMut(&N) -> bool) -> Option<Found<N, C>>
+where N: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output = C>, I: IntoIterator<Item = (N, C)> {
+    astar_multi([start], neighbours, |_| C::default(), is_goal)
+}
+
+#[allow(dead_code)]
+pub fn dijkstra_multi<N, C, I>(starts: impl IntoIterator<Item = N>, neighbours: impl FnMut(&N) -> I, is_goal: impl FnMut(&N) -> bool) -> Option<Found<N, C>>
+where N: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output = C>, I: IntoIterator<Item = (N, C)> {
+    astar_multi(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// Dijkstra guided by `heuristic`, which must never overestimate the remaining cost to a goal.
+#[allow(dead_code)]
+pub fn astar<N, C, I>(start: N, neighbours: impl FnMut(&N) -> I, heuristic: impl FnMut(&N) -> C, is_goal: impl FnMut(&N) -> bool) -> Option<Found<N, C>>
+where N: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output = C>, I: IntoIterator<Item = (N, C)> {
+    astar_multi([start], neighbours, heuristic, is_goal)
+}
+
+#[allow(dead_code)]
+pub fn astar_multi<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool
+) -> Option<Found<N, C>>
+where N: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output = C>, I: IntoIterator<Item = (N, C)> {
+    let mut best: HashMap<N, (C, Option<N>)> = HashMap::new();
+    let mut settled = HashSet::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        best.insert(start.clone(), (C::default(), None));
+        queue.push(Entry { priority: heuristic(&start), cost: C::default(), node: start });
+    }
+
+    while let Some(Entry { cost, node, .. }) = queue.pop() {
+        if !settled.insert(node.clone()) {
+            continue;
+        }
+        if is_goal(&node) {
+            let parents = best.into_iter().map(|(node, (_, parent))| (node, parent)).collect();
+            let mut result = found(node, cost, parents);
+            result.visited = settled;
+            return Some(result);
+        }
+
+        for (next, edge) in neighbours(&node) {
+            let next_cost = cost + edge;
+            if best.get(&next).is_none_or(|&(known, _)| next_cost < known) {
+                best.insert(next.clone(), (next_cost, Some(node.clone())));
+                queue.push(Entry { priority: next_cost + heuristic(&next), cost: next_cost, node: next });
+            }
+        }
+    }
+
+    None
+}
+
+fn found<N: Clone + Eq + Hash, C>(goal: N, cost: C, parents: HashMap<N, Option<N>>) -> Found<N, C> {
+    let mut path = vec![goal];
+    while let Some(Some(parent)) = parents.get(path.last().expect("Should contain the goal")) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+
+    Found { cost, path, visited: parents.into_keys().collect() }
+}
+
+/// A queued node, ordered so that `BinaryHeap` pops the lowest priority first.
+#[allow(dead_code)]
+struct Entry<N, C> {
+    priority: C,
+    cost: C,
+    node: N
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Numbers reachable by adding 1 (cost 1) or doubling (cost 3).
+    fn steps(n: &u32) -> Vec<(u32, u32)> {
+        vec![(n + 1, 1), (n * 2, 3)]
+    }
+
+    #[test]
+    fn bfs_finds_fewest_steps() {
+        let found = bfs(1, |&n| [n + 1, n * 2], |&n| n == 10).unwrap();
+
+        assert_eq!(found.cost, 4);
+        assert_eq!(found.path, vec![1, 2, 4, 5, 10]);
+        assert!(found.visited.contains(&8));
+
+        let nearest = bfs_multi([1, 7], |&n| [n + 1, n * 2], |&n| n == 10).unwrap();
+        assert_eq!(nearest.path, vec![7, 8, 9, 10]);
+        assert!(bfs(1, |&n| (n < 5).then_some(n + 1), |&n| n == 10).is_none());
+    }
+
+    #[test]
+    fn flood_fill_reaches_everything_connected() {
+        let reached = flood_fill([3_u32, 20], |&n| [n + 1, n.saturating_sub(1)].into_iter().filter(|&m| m != 10 && m <= 21));
+
+        assert_eq!(reached.len(), 10 + 11);
+        assert!(reached.contains(&0) && reached.contains(&21) && !reached.contains(&10));
+    }
+
+    #[test]
+    fn dijkstra_and_astar_find_cheapest_path() {
+        let cheapest = dijkstra(1, steps, |&n| n == 10).unwrap();
+        let guided = astar(1, steps, |&n| 10_u32.saturating_sub(n).min(1), |&n| n == 10).unwrap();
+
+        assert_eq!(cheapest.cost, 7);
+        assert_eq!(cheapest.path, vec![1, 2, 3, 4, 5, 10]);
+        assert_eq!((guided.cost, guided.path), (cheapest.cost, cheapest.path));
+        assert_eq!(dijkstra_multi([1, 9], steps, |&n| n == 10).unwrap().path, vec![9, 10]);
+    }
+}
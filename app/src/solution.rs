@@ -0,0 +1,168 @@
+use std::{fmt, time::{Duration, Instant}};
+
+use crate::parse::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+    Render(String),
+    None
+}
+
+impl Answer {
+    pub fn is_multi_line(&self) -> bool {
+        matches!(self, Answer::Render(_))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) | Answer::Render(s) => write!(f, "{}", s),
+            Answer::None => write!(f, "-")
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Int(i64::try_from(n).expect("Answer should fit in an i64"))
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+pub trait Solution {
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+
+    fn part_1(parsed: &Self::Parsed) -> Answer;
+
+    fn part_2(_parsed: &Self::Parsed) -> Answer {
+        Answer::None
+    }
+
+    /// A breakdown of the parsed input for sanity-checking it, shown by `run --report`. It is
+    /// only built when asked for, outside the timings.
+    fn report(_parsed: &Self::Parsed) -> Option<String> {
+        None
+    }
+}
+
+pub struct Stage {
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration
+}
+
+/// Answers for the requested parts, with each stage timed separately.
+pub struct Solved {
+    pub parse: Duration,
+    pub parts: Vec<Stage>,
+    /// Only built when `solve` is asked for it.
+    pub report: Option<String>
+}
+
+impl Solved {
+    pub fn total(&self) -> Duration {
+        self.parse + self.parts.iter().map(|s| s.elapsed).sum::<Duration>()
+    }
+}
+
+pub type Solver = fn(&str, &[u8], bool) -> Result<Solved, ParseError>;
+
+/// Parses `input` and runs the requested parts, then builds the day's report if `report` is set.
+pub fn solve<S: Solution>(input: &str, parts: &[u8], report: bool) -> Result<Solved, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let parts = parts.iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part_1(&parsed),
+                _ => S::part_2(&parsed)
+            };
+            Stage { part, answer, elapsed: start.elapsed() }
+        })
+        .collect();
+
+    let report = if report { S::report(&parsed) } else { None };
+
+    Ok(Solved { parse, parts, report })
+}
+
+/// Reads the fixture `examples/<year>/<name>.txt`.
+#[cfg(test)]
+pub fn example_input(year: u16, name: &str) -> String {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("examples")
+        .join(year.to_string())
+        .join(format!("{}.txt", name));
+
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("Could not read {}: {}", path.display(), e))
+}
+
+/// Parses a fixture with the real `parse` stage, for tests that need the parsed value.
+#[cfg(test)]
+pub fn parse_example<S: Solution>(year: u16, name: &str) -> S::Parsed {
+    let input = example_input(year, name);
+    S::parse(&input).unwrap_or_else(|e| panic!("{}", e.render(&input)))
+}
+
+#[cfg(test)]
+pub fn solve_example<S: Solution>(year: u16, name: &str, part: u8) -> Answer {
+    let parsed = parse_example::<S>(year, name);
+    match part {
+        1 => S::part_1(&parsed),
+        _ => S::part_2(&parsed)
+    }
+}
+
+/// Generates one test per line, running a fixture from `examples/<year>/` through
+/// `parse` and the given part, and comparing with the expected answer:
+///
+/// ```ignore
+/// examples! {
+///     Day06 in 2022;
+///     part_1: "day06-1", part 1 => 7;
+///     part_2: "day06-1", part 2 => 19;
+/// }
+/// ```
+#[cfg(test)]
+#[macro_export]
+macro_rules! examples {
+    ($solution:ident in $year:literal; $($name:ident: $fixture:literal, part $part:literal => $expected:expr;)+) => {
+        $(
+            #[test]
+            fn $name() {
+                assert_eq!(
+                    $crate::solution::solve_example::<$solution>($year, $fixture, $part),
+                    $crate::solution::Answer::from($expected)
+                );
+            }
+        )+
+    };
+}
@@ -0,0 +1,118 @@
+use std::{collections::HashMap, env, fmt, fs, io, path::{Path, PathBuf}};
+
+use crate::solution::Answer;
+
+/// Overrides the directory holding `<year>.toml` answer files.
+pub const ROOT_VAR: &str = "AOC_ANSWERS";
+
+pub fn answers_path(year: u16) -> PathBuf {
+    env::var_os(ROOT_VAR)
+        .map_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("answers"), PathBuf::from)
+        .join(format!("{}.toml", year))
+}
+
+/// Expected answers keyed by `(day, part)`, read from tables like `[day01]` with `part1`/`part2` keys.
+#[derive(Debug, Default)]
+pub struct Answers {
+    expected: HashMap<(u8, u8), toml::Value>
+}
+
+impl Answers {
+    /// A missing file means no answers are known yet.
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Answers::from_toml(&contents).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e))
+        }
+    }
+
+    fn from_toml(contents: &str) -> Result<Answers, String> {
+        let table: toml::Table = contents.parse().map_err(|e: toml::de::Error| e.message().to_string())?;
+        let mut expected = HashMap::new();
+
+        for (key, parts) in table {
+            let day = key.strip_prefix("day")
+                .and_then(|d| d.parse::<u8>().ok())
+                .ok_or_else(|| format!("expected a [dayNN] table, found [{}]", key))?;
+            let parts = parts.as_table().ok_or_else(|| format!("expected [{}] to be a table", key))?;
+            for (name, value) in parts {
+                let part = match name.as_str() {
+                    "part1" => 1,
+                    "part2" => 2,
+                    _ => return Err(format!("expected part1 or part2 in [{}], found {}", key, name))
+                };
+                expected.insert((day, part), value.clone());
+            }
+        }
+
+        Ok(Answers { expected })
+    }
+
+    /// Whether any answer is known for the day.
+    pub fn has_day(&self, day: u8) -> bool {
+        self.expected.keys().any(|&(d, _)| d == day)
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Status {
+        match (self.expected.get(&(day, part)), answer) {
+            (None, _) => Status::Missing,
+            (Some(toml::Value::Integer(expected)), Answer::Int(n)) if expected == n => Status::Pass,
+            (Some(toml::Value::String(expected)), Answer::Text(s) | Answer::Render(s)) if expected.trim_end() == s.trim_end() => Status::Pass,
+            (Some(toml::Value::String(expected)), _) => Status::Fail { expected: expected.clone() },
+            (Some(expected), _) => Status::Fail { expected: expected.to_string() }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Missing
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { .. } => write!(f, "FAIL"),
+            Status::Missing => write!(f, "missing")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[day01]
+part1 = 24000
+part2 = 45000
+
+[day10]
+part2 = '''
+##..
+#...'''
+"#;
+
+    #[test]
+    fn checks_against_known_answers() {
+        let answers = Answers::from_toml(ANSWERS).unwrap();
+
+        assert_eq!(answers.check(1, 1, &Answer::Int(24000)), Status::Pass);
+        assert_eq!(answers.check(1, 2, &Answer::Int(45001)), Status::Fail { expected: "45000".into() });
+        assert_eq!(answers.check(1, 2, &Answer::Text("45000".into())), Status::Fail { expected: "45000".into() });
+        assert_eq!(answers.check(10, 1, &Answer::Int(13140)), Status::Missing);
+        assert_eq!(answers.check(10, 2, &Answer::Render("##..\n#...".into())), Status::Pass);
+        assert!(answers.has_day(10));
+        assert!(!answers.has_day(2));
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        assert!(Answers::from_toml("[day01]\npart3 = 1").is_err());
+        assert!(Answers::from_toml("[one]\npart1 = 1").is_err());
+    }
+}
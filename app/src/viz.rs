@@ -0,0 +1,255 @@
+use std::{fs::File, io::{self, BufWriter, Write}, path::{Path, PathBuf}, sync::{atomic::{AtomicBool, Ordering}, Mutex}};
+
+use crate::grid::Grid;
+
+pub type Rgb = [u8; 3];
+
+/// Frames kept before every other one is dropped and only every second later frame is sampled.
+const MAX_FRAMES: usize = 400;
+/// Frames are scaled up until the longer side reaches about this many pixels.
+const TARGET_SIZE: usize = 600;
+const DELAY_CS: u16 = 4;
+const LAST_DELAY_CS: u16 = 300;
+
+/// One image, stored as indices into its own palette.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+    palette: Vec<Rgb>
+}
+
+impl Frame {
+    /// One pixel per cell, coloured with `palette[colour(cell)]`.
+    pub fn from_grid<T>(grid: &Grid<T>, palette: &[Rgb], colour: impl Fn(&T) -> u8) -> Frame {
+        let pixels: Vec<u8> = grid.iter().map(|(_, cell)| colour(cell)).collect();
+        assert!(pixels.iter().all(|&i| (i as usize) < palette.len()), "colour index outside the palette");
+
+        Frame { width: grid.width(), height: grid.height(), pixels, palette: palette.to_vec() }
+    }
+
+    fn scaled(&self, scale: usize) -> Frame {
+        let pixels = (0..self.height * scale)
+            .flat_map(|y| (0..self.width * scale).map(move |x| (x / scale, y / scale)))
+            .map(|(x, y)| self.pixels[y * self.width + x])
+            .collect();
+
+        Frame { width: self.width * scale, height: self.height * scale, pixels, palette: self.palette.clone() }
+    }
+
+    fn rgb(&self) -> Vec<u8> {
+        self.pixels.iter().flat_map(|&i| self.palette[i as usize]).collect()
+    }
+
+    /// A binary (P6) portable pixmap.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut output = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        output.extend(self.rgb());
+        output
+    }
+
+    pub fn write_png(&self, path: &Path) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(BufWriter::new(File::create(path)?), self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header()
+            .and_then(|mut writer| writer.write_image_data(&self.rgb()))
+            .map_err(io::Error::other)
+    }
+}
+
+fn scale_for(frames: &[Frame]) -> usize {
+    let longest = frames.iter().map(|f| f.width.max(f.height)).max().unwrap_or(1);
+    (TARGET_SIZE / longest.max(1)).clamp(1, 8)
+}
+
+/// Writes the frames as a looping animation, holding the last one for a few seconds.
+pub fn write_gif(path: &Path, frames: &[Frame]) -> io::Result<()> {
+    let scale = scale_for(frames);
+    let width = frames.iter().map(|f| f.width * scale).max().unwrap_or(1);
+    let height = frames.iter().map(|f| f.height * scale).max().unwrap_or(1);
+    if width > u16::MAX as usize || height > u16::MAX as usize {
+        return Err(io::Error::other(format!("{}x{} is too large for a GIF", width, height)));
+    }
+
+    let mut encoder = gif::Encoder::new(BufWriter::new(File::create(path)?), width as u16, height as u16, &[])
+        .map_err(io::Error::other)?;
+    encoder.set_repeat(gif::Repeat::Infinite).map_err(io::Error::other)?;
+
+    for (i, frame) in frames.iter().enumerate() {
+        let scaled = frame.scaled(scale);
+        let palette: Vec<u8> = scaled.palette.iter().flatten().copied().collect();
+        let mut image = gif::Frame::from_palette_pixels(scaled.width as u16, scaled.height as u16, scaled.pixels, palette, None);
+        image.delay = if i + 1 == frames.len() { LAST_DELAY_CS } else { DELAY_CS };
+        encoder.write_frame(&image).map_err(io::Error::other)?;
+    }
+
+    Ok(())
+}
+
+/// Writes `.gif` paths as one animation; for `.png` and `.ppm` writes every frame to a numbered
+/// file next to `path`. Returns the files written.
+pub fn write(path: &Path, frames: &[Frame]) -> io::Result<Vec<PathBuf>> {
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or_default();
+    if extension == "gif" {
+        write_gif(path, frames)?;
+        return Ok(vec![path.to_path_buf()]);
+    }
+
+    let scale = scale_for(frames);
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("frame");
+    let mut written = vec![];
+    for (i, frame) in frames.iter().enumerate() {
+        let numbered = path.with_file_name(format!("{}-{:04}.{}", stem, i, extension));
+        match extension {
+            "png" => frame.scaled(scale).write_png(&numbered)?,
+            "ppm" => File::create(&numbered)?.write_all(&frame.scaled(scale).to_ppm())?,
+            _ => return Err(io::Error::other(format!("expected a .gif, .png or .ppm path, got {}", path.display())))
+        }
+        written.push(numbered);
+    }
+
+    Ok(written)
+}
+
+struct Recorder {
+    /// Each frame with whether it is pinned, which keeps it when the frames are thinned out.
+    frames: Vec<(Frame, bool)>,
+    seen: usize,
+    stride: usize
+}
+
+impl Recorder {
+    fn new() -> Recorder {
+        Recorder { frames: vec![], seen: 0, stride: 1 }
+    }
+
+    fn offer(&mut self, make: impl FnOnce() -> Frame) {
+        self.seen += 1;
+        if !(self.seen - 1).is_multiple_of(self.stride) {
+            return;
+        }
+        self.frames.push((make(), false));
+        if self.frames.len() >= MAX_FRAMES {
+            let mut index = 0;
+            self.frames.retain(|&(_, pinned)| {
+                index += 1;
+                pinned || index % 2 == 1
+            });
+            self.stride *= 2;
+        }
+    }
+
+    fn pin(&mut self, frame: Frame) {
+        self.frames.push((frame, true));
+    }
+}
+
+/// Frames offered by the solutions while an animation is being recorded. Only one day should
+/// record at a time.
+static RECORDER: Mutex<Option<Recorder>> = Mutex::new(None);
+/// Mirrors whether `RECORDER` is set, so offering a frame while not recording skips the lock.
+static RECORDING: AtomicBool = AtomicBool::new(false);
+
+/// Starts collecting frames.
+pub fn start() {
+    *RECORDER.lock().expect("Should not be poisoned") = Some(Recorder::new());
+    RECORDING.store(true, Ordering::Release);
+}
+
+pub fn recording() -> bool {
+    RECORDING.load(Ordering::Acquire)
+}
+
+/// Stops collecting and returns the frames recorded since `start`.
+pub fn finish() -> Vec<Frame> {
+    RECORDING.store(false, Ordering::Release);
+    RECORDER.lock().expect("Should not be poisoned").take()
+        .map_or(vec![], |r| r.frames.into_iter().map(|(frame, _)| frame).collect())
+}
+
+/// Offers a frame. `make` is only called if recording, and only for a sample of the calls once
+/// there are many, so solutions can call this every step.
+pub fn frame(make: impl FnOnce() -> Frame) {
+    if !recording() {
+        return;
+    }
+    if let Some(recorder) = RECORDER.lock().expect("Should not be poisoned").as_mut() {
+        recorder.offer(make);
+    }
+}
+
+/// Records a frame that must not be sampled away, such as the final state.
+pub fn last_frame(make: impl FnOnce() -> Frame) {
+    if !recording() {
+        return;
+    }
+    if let Some(recorder) = RECORDER.lock().expect("Should not be poisoned").as_mut() {
+        recorder.pin(make());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PALETTE: &[Rgb] = &[[0, 0, 0], [255, 255, 255]];
+
+    fn checkerboard() -> Frame {
+        let mut grid = Grid::new(2, 2, false);
+        grid[(0, 0)] = true;
+        grid[(1, 1)] = true;
+        Frame::from_grid(&grid, PALETTE, |&lit| lit as u8)
+    }
+
+    #[test]
+    fn renders_ppm() {
+        let ppm = checkerboard().to_ppm();
+
+        assert!(ppm.starts_with(b"P6\n2 2\n255\n"));
+        assert_eq!(&ppm[11..], &[255, 255, 255, 0, 0, 0, 0, 0, 0, 255, 255, 255]);
+        assert_eq!(checkerboard().scaled(2).pixels, vec![1, 1, 0, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1]);
+    }
+
+    #[test]
+    fn samples_frames_once_there_are_many() {
+        let mut recorder = Recorder::new();
+        let mut made = 0;
+        for _ in 0..MAX_FRAMES * 3 {
+            recorder.offer(|| {
+                made += 1;
+                checkerboard()
+            });
+        }
+
+        assert!(recorder.frames.len() < MAX_FRAMES);
+        assert!(recorder.frames.len() > MAX_FRAMES / 2);
+        assert!(made < MAX_FRAMES * 2);
+    }
+
+    #[test]
+    fn keeps_pinned_frames_while_thinning_out() {
+        let mut recorder = Recorder::new();
+        for _ in 0..MAX_FRAMES - 1 {
+            recorder.offer(checkerboard);
+        }
+        recorder.pin(Frame { palette: vec![[1, 2, 3]], ..checkerboard() });
+        for _ in 0..MAX_FRAMES * 3 {
+            recorder.offer(checkerboard);
+        }
+
+        assert!(recorder.frames.len() < MAX_FRAMES);
+        assert_eq!(recorder.frames.iter().filter(|(frame, _)| frame.palette == [[1, 2, 3]]).count(), 1);
+    }
+
+    #[test]
+    fn writes_gif() {
+        let path = std::env::temp_dir().join(format!("aoc-viz-{}.gif", std::process::id()));
+        write(&path, &[checkerboard(), checkerboard()]).unwrap();
+        let header = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(header.starts_with(b"GIF89a"));
+    }
+}
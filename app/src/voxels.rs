@@ -0,0 +1,108 @@
+use std::{collections::HashSet, hash::Hash};
+
+use crate::{geometry::{Coord, Point3}, search};
+
+/// A set of unit cubes, each named by one corner.
+#[derive(Debug, Clone, Default)]
+pub struct VoxelSet<T> {
+    cubes: HashSet<Point3<T>>
+}
+
+impl<T: Coord + Hash> VoxelSet<T> {
+    pub fn new() -> Self {
+        VoxelSet { cubes: HashSet::new() }
+    }
+
+    /// Returns whether the cube was new.
+    #[allow(dead_code)]
+    pub fn insert(&mut self, cube: Point3<T>) -> bool {
+        self.cubes.insert(cube)
+    }
+
+    pub fn contains(&self, cube: Point3<T>) -> bool {
+        self.cubes.contains(&cube)
+    }
+
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.cubes.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.cubes.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Point3<T>> + '_ {
+        self.cubes.iter().copied()
+    }
+
+    /// The lowest and highest corner of the cuboid holding every cube, or `None` if empty.
+    pub fn bounds(&self) -> Option<(Point3<T>, Point3<T>)> {
+        let mut cubes = self.iter();
+        let first = cubes.next()?;
+        Some(cubes.fold((first, first), |(min, max), c| (
+            Point3::new(min.x.min(c.x), min.y.min(c.y), min.z.min(c.z)),
+            Point3::new(max.x.max(c.x), max.y.max(c.y), max.z.max(c.z))
+        )))
+    }
+
+    /// Faces not shared with another cube of the set, including those facing enclosed pockets.
+    pub fn surface_area(&self) -> usize {
+        self.iter().flat_map(Point3::neighbours6).filter(|&n| !self.contains(n)).count()
+    }
+
+    /// The empty cubes connected to the outside, within one cube of the bounds. Unsigned
+    /// coordinates must therefore start at one or more.
+    pub fn exterior(&self) -> VoxelSet<T> {
+        let Some((min, max)) = self.bounds() else {
+            return VoxelSet::new();
+        };
+        let one = T::ONE;
+        let (min, max) = (Point3::new(min.x - one, min.y - one, min.z - one), Point3::new(max.x + one, max.y + one, max.z + one));
+        let inside = |c: &Point3<T>| (min.x..=max.x).contains(&c.x) && (min.y..=max.y).contains(&c.y) && (min.z..=max.z).contains(&c.z);
+
+        let cubes = search::flood_fill([min], |cube| cube.neighbours6().into_iter().filter(|c| inside(c) && !self.contains(*c)));
+        VoxelSet { cubes }
+    }
+
+    /// Faces that can be reached from outside the set.
+    pub fn exterior_surface_area(&self) -> usize {
+        let exterior = self.exterior();
+        self.iter().flat_map(Point3::neighbours6).filter(|&n| exterior.contains(n)).count()
+    }
+}
+
+impl<T: Coord + Hash> FromIterator<Point3<T>> for VoxelSet<T> {
+    fn from_iter<I: IntoIterator<Item = Point3<T>>>(cubes: I) -> Self {
+        VoxelSet { cubes: cubes.into_iter().collect() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_exposed_faces() {
+        let pair: VoxelSet<i32> = [Point3::new(1, 1, 1), Point3::new(2, 1, 1)].into_iter().collect();
+
+        assert_eq!(pair.surface_area(), 10);
+        assert_eq!(pair.exterior_surface_area(), 10);
+        assert_eq!(pair.bounds(), Some((Point3::new(1, 1, 1), Point3::new(2, 1, 1))));
+    }
+
+    #[test]
+    fn ignores_enclosed_pockets() {
+        // A 3x3x3 block with its centre missing.
+        let hollow: VoxelSet<i32> = (0..27)
+            .map(|i| Point3::new(i % 3, i / 3 % 3, i / 9))
+            .filter(|&c| c != Point3::new(1, 1, 1))
+            .collect();
+
+        assert_eq!(hollow.surface_area(), 54 + 6);
+        assert_eq!(hollow.exterior_surface_area(), 54);
+        assert!(!hollow.exterior().contains(Point3::new(1, 1, 1)));
+        assert_eq!(VoxelSet::<u32>::new().exterior_surface_area(), 0);
+    }
+}
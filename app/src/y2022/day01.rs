@@ -0,0 +1,90 @@
+use std::{cmp::Reverse, fmt::Write};
+use itertools::Itertools;
+
+use crate::{parse::{self, ParseError}, solution::{Answer, Solution}};
+
+pub struct Elf {
+    /// 1-based, in input order.
+    index: usize,
+    items: Vec<u32>
+}
+
+impl Elf {
+    fn total(&self) -> u32 {
+        self.items.iter().sum()
+    }
+}
+
+/// The `k` elves carrying the most calories, most first. Ties keep input order.
+fn top_k(elves: &[Elf], k: usize) -> Vec<&Elf> {
+    elves.iter().sorted_by_key(|e| Reverse(e.total())).take(k).collect()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = Vec<Elf>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut elves = vec![];
+        let mut items = vec![];
+
+        for (i, line) in input.lines().enumerate() {
+            if !line.is_empty() {
+                items.push(parse::number(line, line, "a calorie count").map_err(|e| e.at_line(i + 1))?);
+            } else if !items.is_empty() {
+                elves.push(Elf { index: elves.len() + 1, items: std::mem::take(&mut items) });
+            }
+        }
+        // The last elf is not followed by a blank line unless the file ends with one.
+        if !items.is_empty() {
+            elves.push(Elf { index: elves.len() + 1, items });
+        }
+
+        Ok(elves)
+    }
+
+    fn part_1(elves: &Self::Parsed) -> Answer {
+        top_k(elves, 1).iter().map(|e| e.total()).sum::<u32>().into()
+    }
+
+    fn part_2(elves: &Self::Parsed) -> Answer {
+        top_k(elves, 3).iter().map(|e| e.total()).sum::<u32>().into()
+    }
+
+    fn report(elves: &Self::Parsed) -> Option<String> {
+        let mut report = format!("{:>5}  {:>5}  {:>7}", "elf", "items", "total");
+        for elf in top_k(elves, elves.len()) {
+            write!(report, "\n{:>5}  {:>5}  {:>7}", elf.index, elf.items.len(), elf.total()).expect("Should write to a string");
+        }
+
+        Some(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day01 in 2022;
+        part_1: "day01-1", part 1 => 24000;
+        part_2: "day01-1", part 2 => 45000;
+    }
+
+    #[test]
+    fn keeps_the_last_elf() {
+        let elves = Day01::parse("1\n2\n\n10").unwrap();
+
+        assert_eq!(elves.len(), 2);
+        assert_eq!(top_k(&elves, 1)[0].index, 2);
+        assert_eq!(Day01::parse("1\n\n\n2\n\n").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn reports_elves_by_total() {
+        let report = Day01::report(&Day01::parse("1\n2\n\n10").unwrap()).unwrap();
+
+        assert_eq!(report, "  elf  items    total\n    2      1       10\n    1      2        3");
+    }
+}
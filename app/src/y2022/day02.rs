@@ -1,30 +1,19 @@
-use std::fs;
-use stopwatch::Stopwatch;
-
-#[cfg(debug_assertions)]
-fn get_env() -> &'static str {
-    "DEBUG"
-}
-
-#[cfg(not(debug_assertions))]
-fn get_env() -> &'static str {
-    "RELEASE"
-}
+use crate::{parse::{self, ParseError}, solution::{Answer, Solution}};
 
 #[derive(PartialEq, Clone, Copy, Debug)]
-enum Weapon {
+pub enum Weapon {
     Rock,
     Paper,
     Scissors
 }
 
 impl Weapon {
-    pub fn from_str(str: &str) -> Weapon {
+    pub fn from_str(line: &str, str: &str) -> Result<Weapon, ParseError> {
         match str {
-            "A" | "X" => Weapon::Rock,
-            "B" | "Y" => Weapon::Paper,
-            "C" | "Z" => Weapon::Scissors,
-            _ => panic!("Invalid input")
+            "A" | "X" => Ok(Weapon::Rock),
+            "B" | "Y" => Ok(Weapon::Paper),
+            "C" | "Z" => Ok(Weapon::Scissors),
+            _ => Err(ParseError::at(line, str, "one of A, B, C, X, Y or Z"))
         }
     }
 
@@ -48,7 +37,7 @@ impl Weapon {
 }
 
 #[derive(Debug, Clone)]
-enum Outcome {
+pub enum Outcome {
     Win,
     Loss,
     Draw
@@ -71,34 +60,32 @@ impl Outcome {
 }
 
 #[derive(Debug, Clone)]
-struct Round {
+pub struct Round {
     pub opponent: Weapon,
     pub you: Option<Weapon>,
     pub target_outcome: Option<Outcome>,
     pub your_score: u32
 }
 
-impl From<&str> for Round {
-    fn from(s: &str) -> Self {
+impl Round {
+    pub fn from_str(s: &str) -> Result<Self, ParseError> {
         let choices: Vec<&str> = s.split(char::is_whitespace).collect();
-        if choices.len() != 2 {
-            panic!("Wrong number of arguments.")
+        if choices.len() > 2 {
+            return Err(ParseError::at(s, choices[2], "end of line after two moves"));
         };
 
-        let opponent = Weapon::from_str(choices[0]);
-        let you = Weapon::from_str(choices[1]);
+        let opponent = Weapon::from_str(s, parse::token(s, &choices, 0, "the opponent's move")?)?;
+        let you = Weapon::from_str(s, parse::token(s, &choices, 1, "your move")?)?;
 
-        Round {
+        Ok(Round {
             opponent,
             you: Some(you),
             target_outcome: None,
             your_score: Weapon::get_score(you, opponent)
-        }
+        })
     }
-}
 
-impl Round {
-    pub fn from_expected_outcome(&mut self) -> Round {
+    pub fn with_expected_outcome(&mut self) -> Round {
         if let Some(hand) = self.you {
             match hand {
                 Weapon::Rock => self.target_outcome = Some(Outcome::Loss),
@@ -112,7 +99,7 @@ impl Round {
                 (Weapon::Paper, Outcome::Loss) => Some(Weapon::Rock),
                 (Weapon::Scissors, Outcome::Win) => Some(Weapon::Rock),
                 (Weapon::Scissors, Outcome::Loss) => Some(Weapon::Paper),
-                _ => Some(self.opponent.clone())
+                _ => Some(self.opponent)
             };
             self.your_score = Weapon::get_score(self.you.unwrap(), self.opponent);
         };
@@ -120,68 +107,38 @@ impl Round {
     }
 }
 
-fn part_1(input: String) -> u32 {
-    input.lines().into_iter()
-        .map(|s| Round::from(s.clone()))
-        .map(|r| r.your_score)
-        .sum()
-}
+pub struct Day02;
 
-fn part_2(input: String) -> u32 {
-    input.lines().into_iter()
-        .map(|s| 
-            Round::from(s.clone())
-            .from_expected_outcome()
-        )
-        .map(|r| r.your_score)
-        .sum()
-}
+impl Solution for Day02 {
+    type Parsed = Vec<Round>;
 
-fn main() {
-    let mut sw = Stopwatch::start_new();
-    let input = fs::read_to_string("inputs/2022/day02.txt").expect("Could not read file");
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse::lines(input, Round::from_str)
+    }
 
-    println!("### Day 2 ###");
-    println!("Part 1: {}", part_1(input.clone()));
-    println!("Part 2: {}", part_2(input.clone()));
-    let ms = sw.elapsed();
-    sw.stop();
-    println!("-- {}μs total ({})--", ms.as_micros(), get_env());
+    fn part_1(rounds: &Self::Parsed) -> Answer {
+        rounds.iter()
+            .map(|r| r.your_score)
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part_2(rounds: &Self::Parsed) -> Answer {
+        rounds.iter()
+            .map(|r| r.clone().with_expected_outcome())
+            .map(|r| r.your_score)
+            .sum::<u32>()
+            .into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn part_1() {
-        let input = r#"A Y
-        B X
-        C Z"#;
-        let sum: u32 = input.lines().into_iter()
-            .map(|s| Round::from(s.clone().trim()))
-            .map(|r| r.your_score)
-            .sum();
-
-        assert_eq!(sum, 15);
+    crate::examples! {
+        Day02 in 2022;
+        part_1: "day02-1", part 1 => 15;
+        part_2: "day02-1", part 2 => 12;
     }
-
-    #[test]
-    fn part_2() {
-        let input = r#"A Y
-        B X
-        C Z"#;
-        let rounds: Vec<Round> = input.lines().into_iter()
-            .map(|s| 
-                Round::from(s.clone().trim())
-                .from_expected_outcome()
-            )
-            .collect();
-
-        let sum: u32 = rounds.into_iter()
-            .map(|r| r.your_score)
-            .sum();
-
-        assert_eq!(sum, 12);
-    }
-}
\ No newline at end of file
+}
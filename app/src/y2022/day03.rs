@@ -0,0 +1,93 @@
+use crate::{parse::{self, ParseError}, solution::{Answer, Solution}};
+
+pub struct Backpack {
+    compartment_one: Vec<char>,
+    compartment_two: Vec<char>,
+    raw: Vec<char>
+}
+
+impl Backpack {
+    pub fn from_str(s: &str) -> Result<Backpack, ParseError> {
+        if let Some(i) = s.find(|c: char| !c.is_ascii_alphabetic()) {
+            return Err(ParseError::new("an item letter").at_column(i + 1));
+        }
+
+        let (comp_a, comp_b) = s.split_at(s.len() / 2);
+
+        if comp_a.len() != comp_b.len() {
+            return Err(ParseError::at_end(s, "an even number of items"));
+        }
+
+        Ok(Backpack {
+            compartment_one: comp_a.chars().collect(),
+            compartment_two: comp_b.chars().collect(),
+            raw: s.chars().collect()
+        })
+    }
+
+    pub fn find_duplicate(&self) -> Option<char> {
+        self.compartment_one.iter()
+            .find(|c| self.compartment_two.contains(c)).copied()
+    }
+
+    pub fn find_duplicate_in_group(group: &[Backpack]) -> Option<char> {
+        let start: Vec<char> = group[0].raw.iter()
+        .filter(|c| group[1].raw.contains(c)).copied()
+        .collect();
+
+        start.iter()
+            .find(|c| group[2].raw.contains(c)).copied()
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Vec<Backpack>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse::lines(input, |line| Backpack::from_str(line.trim()))
+    }
+
+    fn part_1(backpacks: &Self::Parsed) -> Answer {
+        backpacks.iter()
+            .map(|backpack| {
+                match backpack.find_duplicate() {
+                    Some(c) => char_to_points(c),
+                    None => panic!("No duplicate")
+                }
+            })
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part_2(backpacks: &Self::Parsed) -> Answer {
+        backpacks.chunks(3)
+            .map(|g| Backpack::find_duplicate_in_group(g).expect("No common char"))
+            .map(char_to_points)
+            .sum::<u32>()
+            .into()
+    }
+}
+
+fn char_to_points(c: char) -> u32 {
+    let mut num: u8 = c as u8;
+    if num >= 97 {
+        num -= 96
+    } else {
+        num -= 38
+    }
+
+    num.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day03 in 2022;
+        part_1: "day03-1", part 1 => 157;
+        part_2: "day03-1", part 2 => 70;
+    }
+}
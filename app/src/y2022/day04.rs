@@ -0,0 +1,87 @@
+use std::ops::RangeInclusive;
+use itertools::Itertools;
+
+use crate::{intervals::IntervalSet, parse::{self, ParseError}, solution::{Answer, Solution}};
+
+pub struct CleanupPair {
+    first: RangeInclusive<u32>,
+    second: RangeInclusive<u32>
+}
+
+impl CleanupPair {
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let pair: Vec<&str> = s.split(',').collect();
+        if pair.len() > 2 {
+            return Err(ParseError::at(s, pair[2], "end of line after two ranges"));
+        }
+
+        let (first, second) = [0, 1].iter()
+            .map(|&i| {
+                let r = parse::token(s, &pair, i, "a range")?;
+                let bounds: Vec<&str> = r.split('-').collect();
+                let start: u32 = parse::number(s, parse::token(s, &bounds, 0, "a section number")?, "a section number")?;
+                let end: u32 = parse::number(s, parse::token(s, &bounds, 1, "a section number")?, "a section number")?;
+                Ok(start..=end)
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?
+            .into_iter()
+            .collect_tuple()
+            .unwrap();
+
+        Ok(CleanupPair { first, second })
+    }
+
+    fn is_full_overlap(&self) -> bool {
+        IntervalSet::from(self.first.clone()).contains_range(&self.second) ||
+        IntervalSet::from(self.second.clone()).contains_range(&self.first)
+    }
+
+    fn is_overlap(&self) -> bool {
+        IntervalSet::from(self.first.clone()).overlaps(&self.second)
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Vec<CleanupPair>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse::lines(input, |l| CleanupPair::from_str(l.trim()))
+    }
+
+    fn part_1(pairs: &Self::Parsed) -> Answer {
+        pairs.iter()
+            .map(|cl| {
+                match cl.is_full_overlap() {
+                    true => 1,
+                    false => 0
+                }
+            })
+            .sum::<i32>()
+            .into()
+    }
+
+    fn part_2(pairs: &Self::Parsed) -> Answer {
+        pairs.iter()
+            .map(|cl| {
+                match cl.is_overlap() {
+                    true => 1,
+                    false => 0
+                }
+            })
+            .sum::<i32>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day04 in 2022;
+        part_1: "day04-1", part 1 => 2;
+        part_2: "day04-1", part 2 => 4;
+    }
+}
@@ -0,0 +1,153 @@
+use itertools::Itertools;
+
+use crate::{parse::{self, ParseError}, solution::{Answer, Solution}};
+
+#[derive(Clone)]
+pub struct Ship {
+    stacks: Vec<(u8, Vec<char>)>
+}
+
+impl Ship {
+    fn from_lines(input: &[&str]) -> Result<Ship, ParseError> {
+        let mut stacks: Vec<(u8, Vec<char>)> = Vec::new();
+
+        for (line, s) in input.iter().enumerate().rev() {
+            for (column, c) in s.char_indices() {
+                if c.is_ascii_digit() {
+                    stacks.push((column as u8, vec![]));
+                } else if c.is_ascii_alphabetic() {
+                    stacks.iter_mut()
+                        .find(|s| s.0 == column as u8)
+                        .ok_or_else(|| ParseError::new("a crate above a stack number")
+                            .at_line(line + 1)
+                            .at_column(column + 1))?.1
+                        .push(c);
+                }
+            }
+        }
+
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            stack.0 = (i + 1) as u8;
+        }
+
+        Ok(Ship { stacks })
+    }
+
+    fn move_crate(&mut self, mv: &Move) {
+        for _ in 0..mv.amount {
+            let from =  &self.stacks.iter_mut()
+                .find(|s| s.0 == mv.from)
+                .expect("Should be there").1.pop().expect("Should be a value here");
+
+            self.stacks.iter_mut()
+                .find(|s| s.0 == mv.to)
+                .expect("Should be there").1.push(*from);
+        }
+    }
+
+    fn move_crates(&mut self, mv: &Move) {
+        let mut crane: Vec<char> = vec![];
+        for _ in 0..mv.amount {
+            let from =  &self.stacks.iter_mut()
+                .find(|s| s.0 == mv.from)
+                .expect("Should be there").1.pop().expect("Should be a value here");
+
+            crane.push(*from);
+        }
+
+        crane.reverse();
+        
+        self.stacks.iter_mut()
+            .find(|s| s.0 == mv.to)
+            .expect("Should be there").1.append(&mut crane);
+    }
+
+    fn get_top_crates(&self) -> String {
+        self.stacks.to_owned().iter_mut()
+            .map(|s| s.1.last().expect("Should be at least one element vec"))
+            .join("")
+    }
+}
+
+pub struct Move {
+    amount: u8,
+    from: u8,
+    to: u8
+}
+
+impl Move {
+    fn from_str(input: &str) -> Result<Move, ParseError> {
+        let split = input.split_whitespace().collect_vec();
+        let field = |i, expected| parse::number(input, parse::token(input, &split, i, expected)?, expected);
+
+        Ok(Move {
+            amount: field(1, "a crate count")?,
+            from: field(3, "a stack number")?,
+            to: field(5, "a stack number")?
+        })
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed = (Ship, Vec<Move>);
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        extract_ship_and_moves(input)
+    }
+
+    fn part_1((ship, moves): &Self::Parsed) -> Answer {
+        let mut ship = ship.clone();
+        for mv in moves {
+            ship.move_crate(mv);
+        }
+
+        ship.get_top_crates().into()
+    }
+
+    fn part_2((ship, moves): &Self::Parsed) -> Answer {
+        let mut ship = ship.clone();
+        for mv in moves {
+            ship.move_crates(mv);
+        }
+
+        ship.get_top_crates().into()
+    }
+}
+
+fn extract_ship_and_moves(input: &str) -> Result<(Ship, Vec<Move>), ParseError> {
+    let lines = input.lines().collect_vec();
+    let blank = lines.iter()
+        .position(|line| line.trim().is_empty())
+        .ok_or_else(|| ParseError::new("a blank line between the crates and the moves").at_line(lines.len()))?;
+
+    let ship = Ship::from_lines(&lines[..blank])?;
+    let moves = lines[blank + 1..].iter()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| Move::from_str(line).map_err(|e| e.at_line(blank + i + 2)))
+        .collect::<Result<Vec<Move>, ParseError>>()?;
+
+    Ok((ship, moves))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day05 in 2022;
+        part_1: "day05-1", part 1 => "CMZ";
+        part_2: "day05-1", part 2 => "MCD";
+    }
+
+    #[test]
+    fn bad_move_reports_position() {
+        let input = "[A]\n 1 \n\nmove 1 from 1 to 1\nmove 1 from x to 1";
+        let error = Day05::parse(input).err().unwrap();
+
+        assert_eq!(error.line, Some(5));
+        assert_eq!(error.column, Some(13));
+    }
+}
@@ -0,0 +1,60 @@
+use std::collections::VecDeque;
+
+use itertools::Itertools;
+
+use crate::{parse::ParseError, solution::{Answer, Solution}};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part_1(signal: &Self::Parsed) -> Answer {
+        get_position_after_n_unique(signal, 4).into()
+    }
+
+    fn part_2(signal: &Self::Parsed) -> Answer {
+        get_position_after_n_unique(signal, 14).into()
+    }
+}
+
+fn get_position_after_n_unique(input: &str, n: usize) -> u32 {
+    let chars = input.char_indices();
+
+    let mut buff: VecDeque<char> = VecDeque::new();
+
+    for c in chars {
+        buff.push_back(c.1);
+
+        if buff.len() > n {
+            buff.pop_front();
+        }
+        
+        if buff.len() == n && buff.iter().all_unique() {
+            return c.0 as u32 + 1
+        }
+    }
+
+    panic!("No marker found")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day06 in 2022;
+        part_1_example_1: "day06-1", part 1 => 7;
+        part_2_example_1: "day06-1", part 2 => 19;
+        part_1_example_2: "day06-2", part 1 => 5;
+        part_2_example_2: "day06-2", part 2 => 23;
+        part_1_example_3: "day06-3", part 1 => 6;
+        part_2_example_3: "day06-3", part 2 => 23;
+        part_1_example_4: "day06-4", part 1 => 11;
+        part_2_example_4: "day06-4", part 2 => 26;
+    }
+}
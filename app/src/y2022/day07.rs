@@ -1,18 +1,8 @@
-use std::fs;
 use itertools::Itertools;
-use stopwatch::Stopwatch;
 
-#[cfg(debug_assertions)]
-fn get_env() -> &'static str {
-    "DEBUG"
-}
-
-#[cfg(not(debug_assertions))]
-fn get_env() -> &'static str {
-    "RELEASE"
-}
+use crate::{parse::{self, ParseError}, solution::{Answer, Solution}};
 
-struct Filesystem {
+pub struct Filesystem {
     directories: Vec<Directory>
 }
 
@@ -21,13 +11,15 @@ impl Filesystem {
         Filesystem { directories: vec![] }
     }
 
-    fn populate(mut self, input: &String) -> Self {
-        let mut iterator = input.lines().into_iter();
+    fn populate(mut self, input: &str) -> Result<Self, ParseError> {
+        let mut iterator = input.lines()
+            .enumerate()
+            .map(|(i, line)| CommandLine::from_str(line).map_err(|e| e.at_line(i + 1)));
 
         let mut current_dir: Vec<String> = vec![];
 
         while let Some(line) = iterator.next() {
-            match CommandLine::from_str(line) {
+            match line? {
                 CommandLine::CD { dir } => {
                     if dir == ".." {
                         current_dir.pop();
@@ -37,8 +29,8 @@ impl Filesystem {
                     }
                 },
                 CommandLine::LS => {
-                    'inner: while let Some(output) = iterator.next() {
-                        match CommandLine::from_str(output) {
+                    'inner: for output in iterator.by_ref() {
+                        match output? {
                             CommandLine::Output { content } => {
                                 match content {
                                     Content::File { size } => {
@@ -67,8 +59,8 @@ impl Filesystem {
                 _ => break
             }
         }
-        
-        self
+
+        Ok(self)
     }
 }
 
@@ -79,12 +71,11 @@ enum Content {
 }
 
 impl Content {
-    fn from_str(s: &str) -> Content {
+    fn from_str(s: &str) -> Result<Content, ParseError> {
         let split = s.split(char::is_whitespace).collect_vec();
-        if let Ok(size) = split[0].parse::<u32>() {
-            Content::File { size }
-        } else {
-            Content::Directory { dir: Directory { path: String::new(), file_sizes: vec![] }, size: 0 }
+        match parse::token(s, &split, 0, "a file size or dir")? {
+            "dir" => Ok(Content::Directory { dir: Directory { path: String::new(), file_sizes: vec![] }, size: 0 }),
+            size => Ok(Content::File { size: parse::number(s, size, "a file size or dir")? })
         }
     }
 }
@@ -97,12 +88,16 @@ enum CommandLine {
 }
 
 impl CommandLine {
-    fn from_str(s: &str) -> CommandLine {
+    fn from_str(s: &str) -> Result<CommandLine, ParseError> {
         let parts = s.split(char::is_whitespace).collect_vec();
-        match parts[1] {
-            "cd" => CommandLine::CD { dir: parts[2].into() },
-            "ls" => CommandLine::LS,
-            _ => CommandLine::Output { content: Content::from_str(s) }
+        if parts[0] != "$" {
+            return Ok(CommandLine::Output { content: Content::from_str(s)? });
+        }
+
+        match parse::token(s, &parts, 1, "a command")? {
+            "cd" => Ok(CommandLine::CD { dir: parse::token(s, &parts, 2, "a directory")?.into() }),
+            "ls" => Ok(CommandLine::LS),
+            command => Err(ParseError::at(s, command, "cd or ls"))
         }
     }
 }
@@ -113,128 +108,28 @@ struct Directory {
     file_sizes: Vec<u32>
 }
 
-fn part_1(filesystem: &Filesystem) -> u32 {
-    filesystem.directories.iter()
-        .map(|d| {
-            filesystem.directories.iter()
-                .filter(|&di| di.path.starts_with(d.path.as_str()))
-                .map(|dii| dii.file_sizes.iter().sum::<u32>()).sum::<u32>()
-        })
-        .filter(|&s| s <= 100000)
-        .sum()
-}
-
-fn part_2(filesystem: &Filesystem) -> u32 {
-    let dirs: Vec<u32> = filesystem.directories.iter()
-        .map(|d| {
-            filesystem.directories.iter()
-                .filter(|&di| di.path.starts_with(d.path.as_str()))
-                .map(|dii| dii.file_sizes.iter().sum::<u32>()).sum::<u32>()
-        }).sorted().collect();
-
-    let tot_size = 70000000;
-    let used_size = dirs.iter().sorted_by(|a, b| a.cmp(b)).last().unwrap();
-    let target_free = 30000000;
-    let result = dirs.iter().filter(|&s| tot_size - used_size + s >= target_free)
-        .sorted_by(|a, b| b.cmp(a))
-        .last().unwrap();
-
-    let d_dir = filesystem.directories.iter().map(|d| {
-        let size = filesystem.directories.iter()
-            .filter(|&di| di.path.starts_with(d.path.as_str()))
-            .map(|dii| dii.file_sizes.iter().sum::<u32>()).sum::<u32>();
-
-        (d.path.clone(), size)
-    }).find(|x| x.1 == *result)
-    .unwrap();
-
-    d_dir.1
-}
-
-fn main() {
-    let mut sw = Stopwatch::start_new();
-    let input = fs::read_to_string("inputs/2022/day07.txt").expect("Could not read file");
+pub struct Day07;
 
-    let filesystem = Filesystem::new().populate(&input);
-    
-    println!("### Day 7 ###");
-    println!("# Part 1: {}", part_1(&filesystem));
-    println!("# Part 2: {}", part_2(&filesystem));
-    let ms = sw.elapsed();
-    sw.stop();
-    println!("-- {}μs total ({})--", ms.as_micros(), get_env());
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl Solution for Day07 {
+    type Parsed = Filesystem;
 
-    #[test]
-    fn part_1() {
-        let input = r#"$ cd /
-$ ls
-dir a
-14848514 b.txt
-8504156 c.dat
-dir d
-$ cd a
-$ ls
-dir e
-29116 f
-2557 g
-62596 h.lst
-$ cd e
-$ ls
-584 i
-$ cd ..
-$ cd ..
-$ cd d
-$ ls
-4060174 j
-8033020 d.log
-5626152 d.ext
-7214296 k"#.to_string();
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Filesystem::new().populate(input)
+    }
 
-        let filesystem = Filesystem::new().populate(&input);
-        let result: u32 = filesystem.directories.iter()
+    fn part_1(filesystem: &Self::Parsed) -> Answer {
+        filesystem.directories.iter()
             .map(|d| {
                 filesystem.directories.iter()
                     .filter(|&di| di.path.starts_with(d.path.as_str()))
                     .map(|dii| dii.file_sizes.iter().sum::<u32>()).sum::<u32>()
             })
             .filter(|&s| s <= 100000)
-            .sum();
-
-        assert_eq!(result, 95437);
+            .sum::<u32>()
+            .into()
     }
 
-    #[test]
-    fn part_2() {
-        let input = r#"$ cd /
-$ ls
-dir a
-14848514 b.txt
-8504156 c.dat
-dir d
-$ cd a
-$ ls
-dir e
-29116 f
-2557 g
-62596 h.lst
-$ cd e
-$ ls
-584 i
-$ cd ..
-$ cd ..
-$ cd d
-$ ls
-4060174 j
-8033020 d.log
-5626152 d.ext
-7214296 k"#.to_string();
-
-        let filesystem = Filesystem::new().populate(&input);
+    fn part_2(filesystem: &Self::Parsed) -> Answer {
         let dirs: Vec<u32> = filesystem.directories.iter()
             .map(|d| {
                 filesystem.directories.iter()
@@ -258,6 +153,17 @@ $ ls
         }).find(|x| x.1 == *result)
         .unwrap();
 
-        assert_eq!(d_dir.1, 24933642);
+        d_dir.1.into()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day07 in 2022;
+        part_1: "day07-1", part 1 => 95437;
+        part_2: "day07-1", part 2 => 24933642;
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{grid::Grid, parse::ParseError, solution::{Answer, Solution}};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Grid::parse(input, "a tree height", |c| c.to_digit(10))
+    }
+
+    fn part_1(map: &Self::Parsed) -> Answer {
+        map.positions()
+            .filter(|&(x, y)| lines_of_sight(map, x, y).iter().any(|trees| trees.iter().all(|&t| t < map[(x, y)])))
+            .count()
+            .into()
+    }
+
+    fn part_2(map: &Self::Parsed) -> Answer {
+        map.positions()
+            .map(|(x, y)| get_scenic_score(map, x, y))
+            .max()
+            .unwrap_or(0)
+            .into()
+    }
+}
+
+/// The trees seen looking left, right, up and down from `(x, y)`, nearest first.
+fn lines_of_sight(map: &Grid<u32>, x: usize, y: usize) -> [Vec<u32>; 4] {
+    let row = map.row(y);
+    let column: Vec<u32> = map.column(x).copied().collect();
+
+    [
+        row[..x].iter().rev().copied().collect(),
+        row[x + 1..].to_vec(),
+        column[..y].iter().rev().copied().collect(),
+        column[y + 1..].to_vec()
+    ]
+}
+
+fn get_scenic_score(map: &Grid<u32>, x: usize, y: usize) -> u32 {
+    let height = map[(x, y)];
+
+    lines_of_sight(map, x, y).iter()
+        .map(|trees| match trees.iter().position(|&t| t >= height) {
+            Some(blocked) => blocked as u32 + 1,
+            None => trees.len() as u32
+        })
+        .product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day08 in 2022;
+        part_1: "day08-1", part 1 => 21;
+        part_2: "day08-1", part 2 => 8;
+    }
+}
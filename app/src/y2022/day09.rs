@@ -0,0 +1,113 @@
+use std::collections::HashSet;
+use itertools::Itertools;
+
+use crate::{geometry::{BoundingBox, Dir4, Point2}, grid::Grid, parse::{self, ParseError}, solution::{Answer, Solution}, viz::{self, Frame, Rgb}};
+
+type Knot = Point2<i32>;
+
+/// Background, visited by the tail, knot, head.
+const PALETTE: &[Rgb] = &[[20, 24, 32], [60, 90, 140], [230, 230, 230], [230, 80, 60]];
+
+pub struct Move {
+    direction: Dir4,
+    steps: u32
+}
+
+impl Move {
+    fn from_str(s: &str) -> Result<Move, ParseError> {
+        let parts = s.split(char::is_whitespace).collect_vec();
+        let direction = match parse::token(s, &parts, 0, "a direction")? {
+            "U" => Dir4::Up,
+            "D" => Dir4::Down,
+            "L" => Dir4::Left,
+            "R" => Dir4::Right,
+            other => return Err(ParseError::at(s, other, "one of U, D, L or R"))
+        };
+        let steps = parse::number(s, parse::token(s, &parts, 1, "a step count")?, "a step count")?;
+
+        Ok(Move { direction, steps })
+    }
+}
+
+/// Pulls a rope of `length` knots along the moves and counts the squares its tail visits.
+fn tail_positions(moves: &[Move], length: usize) -> usize {
+    let mut rope: Vec<Knot> = vec![Knot::default(); length];
+    let mut visited: HashSet<Knot> = HashSet::from([Knot::default()]);
+    // The knots never leave the area the head covers.
+    let bounds = viz::recording().then(|| head_bounds(moves));
+
+    for mv in moves {
+        for _ in 0..mv.steps {
+            rope[0] += mv.direction.offset();
+            for j in 1..length {
+                if rope[j].chebyshev(rope[j - 1]) > 1 {
+                    let pull = (rope[j - 1] - rope[j]).signum();
+                    rope[j] += pull;
+                }
+            }
+            visited.insert(rope[length - 1]);
+            if let Some(bounds) = bounds {
+                viz::frame(|| rope_frame(bounds, &rope, &visited));
+            }
+        }
+    }
+    if let Some(bounds) = bounds {
+        viz::last_frame(|| rope_frame(bounds, &rope, &visited));
+    }
+
+    visited.len()
+}
+
+fn head_bounds(moves: &[Move]) -> BoundingBox<i32> {
+    let mut head = Knot::default();
+    let mut bounds = BoundingBox { min: head, max: head };
+    for mv in moves {
+        head += mv.direction.offset() * mv.steps as i32;
+        bounds = bounds.including(head);
+    }
+
+    bounds
+}
+
+fn rope_frame(bounds: BoundingBox<i32>, rope: &[Knot], visited: &HashSet<Knot>) -> Frame {
+    let mut colours = Grid::new(bounds.width() as usize, bounds.height() as usize, 0);
+    let cell = |knot: Knot| Point2::new((knot.x - bounds.min.x) as usize, (knot.y - bounds.min.y) as usize);
+    for &knot in visited {
+        colours[cell(knot)] = 1;
+    }
+    for (i, &knot) in rope.iter().enumerate().rev() {
+        colours[cell(knot)] = if i == 0 { 3 } else { 2 };
+    }
+
+    Frame::from_grid(&colours, PALETTE, |&colour| colour)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed = Vec<Move>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse::lines(input, Move::from_str)
+    }
+
+    fn part_1(moves: &Self::Parsed) -> Answer {
+        tail_positions(moves, 2).into()
+    }
+
+    fn part_2(moves: &Self::Parsed) -> Answer {
+        tail_positions(moves, 10).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day09 in 2022;
+        part_1: "day09-1", part 1 => 13;
+        part_2: "day09-1", part 2 => 1;
+        part_2_larger_example: "day09-2", part 2 => 36;
+    }
+}
@@ -0,0 +1,134 @@
+use itertools::Itertools;
+
+use crate::{grid::Grid, ocr, parse::{self, ParseError}, solution::{Answer, Solution}, viz::{self, Frame, Rgb}};
+
+const WIDTH: usize = 40;
+const HEIGHT: usize = 6;
+/// Dark, then lit.
+const PALETTE: &[Rgb] = &[[16, 16, 16], [120, 255, 120]];
+
+#[derive(Debug, Clone)]
+struct Crt {
+    pixels: Grid<bool>
+}
+
+impl Crt {
+    fn new() -> Crt {
+        Crt { pixels: Grid::new(WIDTH, HEIGHT, false) }
+    }
+
+    fn process(&mut self, instructions: &Vec<Instruction>) {
+        let mut cycle = 0;
+        let mut register: i32 = 1;
+
+        for instruction in instructions {
+            let (cycles, x) = match instruction {
+                Instruction::Noop => (1, 0),
+                Instruction::AddX { x } => (2, *x)
+            };
+            for _ in 0..cycles {
+                self.draw(cycle, register);
+                cycle += 1;
+            }
+            register += x;
+        }
+    }
+
+    /// Lights the pixel drawn during `cycle` (counting from 0) if the sprite covers it.
+    fn draw(&mut self, cycle: usize, register: i32) {
+        let (x, y) = (cycle % WIDTH, cycle / WIDTH);
+        if y < HEIGHT && (x as i32 - register).abs() <= 1 {
+            self.pixels[(x, y)] = true;
+        }
+        viz::frame(|| self.frame());
+    }
+
+    fn frame(&self) -> Frame {
+        Frame::from_grid(&self.pixels, PALETTE, |&lit| lit as u8)
+    }
+
+    fn render(&self) -> String {
+        self.pixels.map(|&lit| if lit { '#' } else { '.' }).to_string()
+    }
+}
+
+pub enum Instruction {
+    Noop,
+    AddX { x: i32 }
+}
+
+impl Instruction {
+    fn from_str(s: &str) -> Result<Instruction, ParseError> {
+        let parts = s.split(char::is_whitespace).collect_vec();
+        match parse::token(s, &parts, 0, "an instruction")? {
+            "addx" => Ok(Instruction::AddX { x: parse::number(s, parse::token(s, &parts, 1, "a number")?, "a number")? }),
+            "noop" => Ok(Instruction::Noop),
+            other => Err(ParseError::at(s, other, "addx or noop"))
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse::lines(input, Instruction::from_str)
+    }
+
+    fn part_1(instructions: &Self::Parsed) -> Answer {
+        let mut cycle = 1;
+        let return_cycles: Vec<i32> = vec![20, 60, 100, 140, 180, 220];
+        let mut register = 1;
+        let mut signals: Vec<i32> = vec![];
+
+        for instruction in instructions {
+            match instruction {
+                Instruction::Noop => {
+                    cycle += 1;
+                    if return_cycles.contains(&cycle) {
+                        signals.push(cycle * register);
+                    }
+                },
+                Instruction::AddX { x } => {
+                    cycle += 1;
+                    if return_cycles.contains(&cycle) {
+                        signals.push(cycle * register);
+                    }
+                    register += x;
+                    cycle += 1;
+                    if return_cycles.contains(&cycle) {
+                        signals.push(cycle * register);
+                    }
+                }
+            }
+        }
+
+        signals.iter().sum::<i32>().into()
+    }
+
+    fn part_2(instructions: &Self::Parsed) -> Answer {
+        let mut crt: Crt = Crt::new();
+
+        crt.process(instructions);
+        viz::last_frame(|| crt.frame());
+
+        let render = crt.render();
+        match ocr::read(&render) {
+            Some(letters) => Answer::Text(letters),
+            None => Answer::Render(render)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day10 in 2022;
+        part_1: "day10-1", part 1 => 13140;
+        part_2: "day10-1", part 2 => Answer::Render("##..##..##..##..##..##..##..##..##..##..\n###...###...###...###...###...###...###.\n####....####....####....####....####....\n#####.....#####.....#####.....#####.....\n######......######......######......####\n#######.......#######.......#######.....".into());
+    }
+}
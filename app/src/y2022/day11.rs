@@ -0,0 +1,202 @@
+use std::{collections::VecDeque, cmp::Reverse, str::FromStr};
+use itertools::Itertools;
+
+use crate::{parse::{self, ParseError}, solution::{Answer, Solution}};
+
+type Item = u128;
+
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+    None,
+    Add { target: OperationTarget },
+    Multiply { target: OperationTarget }
+}
+
+impl Operation {
+    fn from_line(line: &str) -> Result<Operation, ParseError> {
+        let parts = line.split_whitespace().collect_vec();
+        let operator = parse::token(line, &parts, 4, "an operator")?;
+        let target = OperationTarget::from_str(line, parse::token(line, &parts, 5, "an operand")?)?;
+        match operator {
+            "+" => Ok(Operation::Add { target }),
+            "*" => Ok(Operation::Multiply { target }),
+            _ => Err(ParseError::at(line, operator, "+ or *"))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum OperationTarget {
+    Number { n: u128 },
+    Own
+}
+
+impl OperationTarget {
+    fn from_str(line: &str, s: &str) -> Result<OperationTarget, ParseError> {
+        match s {
+            "old" => Ok(OperationTarget::Own),
+            _ => Ok(OperationTarget::Number { n: parse::number(line, s, "a number or old")? })
+        }
+    }
+}
+
+struct Throw {
+    target: i32,
+    item: u128
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    id: u32,
+    items: VecDeque<Item>,
+    operation: Operation,
+    test_devisable_by: i32,
+    throw_to_true: i32,
+    throw_to_false: i32,
+    inspections: u64
+}
+
+impl Monkey {
+    fn new() -> Monkey {
+        Monkey { id: 0, items: VecDeque::new(), operation: Operation::None, test_devisable_by: 1, throw_to_true: 0, throw_to_false: 0, inspections: 0 }
+    }
+
+    /// Parses one monkey block. Line numbers in errors are relative to the block.
+    fn from_lines(lines: &[&str]) -> Result<Monkey, ParseError> {
+        let mut monkey = Monkey::new();
+        for i in 0..6 {
+            let l = lines.get(i)
+                .ok_or_else(|| ParseError::new("another line describing the monkey").at_line(i + 1))?;
+            monkey.parse_line(i, l).map_err(|e| e.at_line(i + 1))?;
+        }
+
+        Ok(monkey)
+    }
+
+    fn parse_line(&mut self, i: usize, l: &str) -> Result<(), ParseError> {
+        match i {
+            0 => self.id = parse::number(l, last_token(l, "a monkey id")?.trim_end_matches(':'), "a monkey id")?,
+            1 => {
+                let (_, items) = l.split_once(':').ok_or_else(|| ParseError::at_end(l, "a colon"))?;
+                for item in items.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()) {
+                    self.items.push_back(parse::number(l, item, "a worry level")?);
+                }
+            },
+            2 => self.operation = Operation::from_line(l)?,
+            3 => self.test_devisable_by = last_number(l, "a divisor")?,
+            4 => self.throw_to_true = last_number(l, "a monkey id")?,
+            _ => self.throw_to_false = last_number(l, "a monkey id")?
+        }
+
+        Ok(())
+    }
+
+    fn inspect_and_return_target(&mut self, part: u8, common_div: i32) -> Throw {
+        self.inspections += 1;
+        self.perform_operation();
+        if part == 1 {
+            self.items[0] = ((self.items[0] as f64) / 3_f64).floor() as u128;
+        } else {
+            self.items[0] %= common_div as u128;
+        }
+        match self.items[0] as i32 % self.test_devisable_by == 0 {
+            true => Throw { target: self.throw_to_true, item: self.items.pop_front().expect("msg") },
+            false => Throw { target: self.throw_to_false, item: self.items.pop_front().expect("msg") }
+        }
+    }
+
+    fn perform_operation(&mut self) {
+        match self.operation {
+            Operation::Add { target } => match target {
+                OperationTarget::Number { n } => self.items[0] += n,
+                OperationTarget::Own => self.items[0] += self.items[0]
+            },
+            Operation::Multiply { target } => match target {
+                OperationTarget::Number { n } => self.items[0] *= n,
+                OperationTarget::Own => self.items[0] = self.items[0].pow(2)
+            },
+            Operation::None => panic!()
+        };
+    }
+}
+
+fn last_token<'a>(line: &'a str, expected: &str) -> Result<&'a str, ParseError> {
+    line.split_whitespace().last().ok_or_else(|| ParseError::at_end(line, expected))
+}
+
+fn last_number<T: FromStr>(line: &str, expected: &str) -> Result<T, ParseError> {
+    parse::number(line, last_token(line, expected)?, expected)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.lines().collect_vec()
+            .chunks(7)
+            .enumerate()
+            .map(|(i, c)| Monkey::from_lines(c).map_err(|e| e.offset(i * 7)))
+            .collect()
+    }
+
+    fn part_1(monkeys: &Self::Parsed) -> Answer {
+        let mut monkeys = monkeys.clone();
+
+        let common_div = monkeys.iter().map(|m| m.test_devisable_by)
+            .product::<i32>();
+
+        for round in 1.. {
+            for m in 0..monkeys.len() {
+                for _ in 0..monkeys[m].items.len() {
+                    let throw = monkeys[m].inspect_and_return_target(1, common_div);
+                    monkeys[throw.target as usize].items.push_back(throw.item);
+                }
+            }
+
+            if round == 20 {
+                break;
+            }
+        }
+
+        monkeys.sort_by_key(|m| Reverse(m.inspections));
+
+        (monkeys[0].inspections * monkeys[1].inspections).into()
+    }
+
+    fn part_2(monkeys: &Self::Parsed) -> Answer {
+        let mut monkeys = monkeys.clone();
+
+        let common_div = monkeys.iter().map(|m| m.test_devisable_by)
+            .product::<i32>();
+
+        for round in 1.. {
+            for m in 0..monkeys.len() {
+                for _ in 0..monkeys[m].items.len() {
+                    let throw = monkeys[m].inspect_and_return_target(2, common_div);
+                    monkeys[throw.target as usize].items.push_back(throw.item);
+                }
+            }
+
+            if round == 10000 {
+                break;
+            }
+        }
+
+        monkeys.sort_by_key(|m| Reverse(m.inspections));
+
+        (monkeys[0].inspections * monkeys[1].inspections).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day11 in 2022;
+        part_1: "day11-1", part 1 => 10605;
+        part_2: "day11-1", part 2 => 2713310158_i64;
+    }
+}
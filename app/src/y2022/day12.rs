@@ -0,0 +1,156 @@
+use crate::{geometry::Point2, grid::Grid, parse::ParseError, search, solution::{Answer, Solution}, viz::{self, Frame, Rgb}};
+
+type Position = Point2<usize>;
+
+/// Palette index of the path; indices below it are elevations.
+const TRAIL: u8 = 26;
+
+fn elevation(square: char) -> u8 {
+    match square {
+        'S' => 0,
+        'E' => 25,
+        c => c as u8 - b'a'
+    }
+}
+
+/// Elevations from dark green to white, then the path in red.
+fn palette() -> Vec<Rgb> {
+    (0..26_u16)
+        .map(|e| [(40 + e * 8) as u8, (90 + e * 6) as u8, (40 + e * 8) as u8])
+        .chain([[220, 40, 40]])
+        .collect()
+}
+
+pub struct Map {
+    squares: Grid<char>
+}
+
+impl Map {
+    fn new(input: &str) -> Result<Map, ParseError> {
+        let squares = Grid::parse(input, "a height from a to z, S or E", |c| match c {
+            'a'..='z' | 'S' | 'E' => Some(c),
+            _ => None
+        })?;
+        for marker in ['S', 'E'] {
+            if squares.find(|&c| c == marker).is_none() {
+                return Err(ParseError::new(format!("a square marked {}", marker)));
+            }
+        }
+
+        Ok(Map { squares })
+    }
+
+    fn is_reachable(&self, to: Position, from: Position) -> bool {
+        elevation(self.squares[to]) <= elevation(self.squares[from]) + 1
+    }
+
+    fn find(&self, value: char) -> Position {
+        self.squares.find(|&c| c == value).map(Point2::from).unwrap_or_else(|| panic!("Should be a square marked {}", value))
+    }
+
+    fn neighbours(&self, from: Position) -> impl Iterator<Item = Position> + '_ {
+        self.squares.neighbours4(from.x, from.y)
+            .map(Point2::from)
+            .filter(move |&to| self.is_reachable(to, from))
+    }
+
+    fn path(&self, starts: impl IntoIterator<Item = Position>) -> Vec<Position> {
+        let target = self.find('E');
+
+        search::bfs_multi(starts, |&p| self.neighbours(p), |&p| p == target)
+            .expect("Should be a path to the target")
+            .path
+    }
+
+    fn shortest_path(&self, starts: impl IntoIterator<Item = Position>) -> usize {
+        let path = self.path(starts);
+        for end in 1..path.len() {
+            viz::frame(|| self.trail_frame(&path[..end]));
+        }
+        viz::last_frame(|| self.trail_frame(&path));
+
+        path.len() - 1
+    }
+
+    /// The map with the trail drawn as arrows towards the target, which is marked `E`.
+    fn trail(&self, trail: &[Position]) -> Grid<char> {
+        let mut squares = self.squares.map(|_| '.');
+        for step in trail.windows(2) {
+            let (from, to) = (step[0], step[1]);
+            squares[from] = if to.x > from.x {
+                '>'
+            } else if to.x < from.x {
+                '<'
+            } else if to.y > from.y {
+                'v'
+            } else {
+                '^'
+            };
+        }
+        if let Some(&end) = trail.last() {
+            squares[end] = 'E';
+        }
+
+        squares
+    }
+
+    fn trail_frame(&self, trail: &[Position]) -> Frame {
+        let mut colours = self.squares.map(|&square| elevation(square));
+        for &position in trail {
+            colours[position] = TRAIL;
+        }
+
+        Frame::from_grid(&colours, &palette(), |&colour| colour)
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Map;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Map::new(input)
+    }
+
+    fn part_1(map: &Self::Parsed) -> Answer {
+        map.shortest_path([map.find('S')]).into()
+    }
+
+    fn part_2(map: &Self::Parsed) -> Answer {
+        let starts = map.squares.iter()
+            .filter(|(_, &c)| c == 'a' || c == 'S')
+            .map(|(position, _)| Point2::from(position));
+
+        map.shortest_path(starts).into()
+    }
+
+    fn report(map: &Self::Parsed) -> Option<String> {
+        Some(map.trail(&map.path([map.find('S')])).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day12 in 2022;
+        part_1: "day12-1", part 1 => 31;
+        part_2: "day12-1", part 2 => 29;
+    }
+
+    #[test]
+    fn needs_a_start_and_an_end() {
+        assert!(matches!(Day12::parse("abc\nSbc"), Err(e) if e.to_string() == "expected a square marked E"));
+        assert!(Day12::parse("Sbc\nabE").is_ok());
+    }
+
+    #[test]
+    fn draws_the_trail() {
+        let map = crate::solution::parse_example::<Day12>(2022, "day12-1");
+        let trail = map.trail(&map.path([map.find('S')])).to_string();
+
+        assert_eq!(trail, ">>vv<<<<\n..vvv<<^\n..vv>E^^\n..v>>>^^\n..>>>>>^");
+    }
+}
@@ -0,0 +1,132 @@
+use std::{collections::VecDeque, cmp::Ordering};
+use itertools::Itertools;
+use serde_json::Value;
+
+use crate::{parse::ParseError, solution::{Answer, Solution}};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PacketPart {
+    Int(i64),
+    List(VecDeque<PacketPart>)
+}
+
+impl Ord for PacketPart {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.clone(), other.clone()) {
+            (PacketPart::Int(n_l), PacketPart::Int(n_r)) => n_l.cmp(&n_r),
+            (PacketPart::List(mut arr_l), PacketPart::List(mut arr_r)) => {
+                while let Some(l) = arr_l.pop_front() {
+                    if let Some(r) = arr_r.pop_front() {
+                        let o = l.cmp(&r); 
+                        match o {
+                            Ordering::Equal => continue,
+                            _ => return o
+                        }
+                    } else {
+                        return Ordering::Greater;
+                    }
+                }
+
+                if !arr_r.is_empty() {
+                    return Ordering::Less;
+                }
+
+                Ordering::Equal
+            },
+            (PacketPart::List(_), PacketPart::Int(_)) => self.cmp(&PacketPart::List(vec![other.clone()].into())),
+            (PacketPart::Int(_), PacketPart::List(_)) => PacketPart::List(vec![self.clone()].into()).cmp(other)
+        }
+    }
+}
+
+impl PartialOrd for PacketPart {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PacketPart {
+    fn from_str(s: &str) -> Result<VecDeque<PacketPart>, ParseError> {
+        let json_val: Value = serde_json::from_str(s)
+            .map_err(|e| ParseError::new("a packet").at_column(e.column()))?;
+        let arr = json_val.as_array().ok_or_else(|| ParseError::at(s, s, "a list"))?;
+
+        PacketPart::from_value_vec(arr)
+    }
+
+    fn from_value_vec(v: &[Value]) -> Result<VecDeque<PacketPart>, ParseError> {
+        v.iter()
+            .map(|v| match v {
+                Value::Number(n) => n.as_i64()
+                    .map(PacketPart::Int)
+                    .ok_or_else(|| ParseError::new("an integer")),
+                Value::Array(arr) => Ok(PacketPart::List(PacketPart::from_value_vec(arr)?)),
+                _ => Err(ParseError::new("a number or a list"))
+            })
+            .collect()
+    }
+
+    fn is_divider_packet(&self) -> bool {
+        *self == PacketPart::List(VecDeque::from([PacketPart::List(VecDeque::from([PacketPart::Int(2)]))])) ||
+            *self == PacketPart::List(VecDeque::from([PacketPart::List(VecDeque::from([PacketPart::Int(6)]))]))
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<PacketPart>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.lines()
+            .enumerate()
+            .filter(|(_, l)| !l.is_empty())
+            .map(|(i, l)| PacketPart::from_str(l)
+                .map(PacketPart::List)
+                .map_err(|e| e.at_line(i + 1)))
+            .collect()
+    }
+
+    fn part_1(packets: &Self::Parsed) -> Answer {
+        packets.chunks(2)
+            .map(|c| (&c[0], &c[1]))
+            .enumerate()
+            .map(|(i, (left, right))| (i + 1, left.cmp(right)))
+            .filter(|(_, order)| order.is_lt())
+            .fold(0, |mut acc, (i, _)| {
+                acc += i as u32;
+                acc
+            })
+            .into()
+    }
+
+    fn part_2(packets: &Self::Parsed) -> Answer {
+        let dividers = ["[[2]]", "[[6]]"].iter()
+            .map(|l| PacketPart::List(PacketPart::from_str(l).expect("Divider packets should parse")))
+            .collect_vec();
+
+        packets.iter()
+            .chain(dividers.iter())
+            .sorted_by(|a, b| a.cmp(b))
+            .enumerate()
+            .fold(1, |mut acc, (index, part)| {
+                if part.is_divider_packet() {
+                    acc *= index as u32 + 1;
+                }
+
+                acc
+            })
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day13 in 2022;
+        part_1: "day13-1", part 1 => 13;
+        part_2: "day13-1", part 2 => 140;
+    }
+}
@@ -0,0 +1,183 @@
+use std::fmt;
+use itertools::Itertools;
+
+use crate::{geometry::{BoundingBox, Point2}, grid::Grid, parse::{self, ParseError}, solution::{Answer, Solution}, viz::{self, Frame, Rgb}};
+
+type Coordinate = Point2<usize>;
+
+fn coordinate(line: &str, s: &str) -> Result<Coordinate, ParseError> {
+    let parts = s.split(',').collect_vec();
+    let x = parse::number(line, parse::token(line, &parts, 0, "an x coordinate")?, "an x coordinate")?;
+    let y = parse::number(line, parse::token(line, &parts, 1, "a y coordinate")?, "a y coordinate")?;
+
+    Ok(Point2::new(x, y))
+}
+
+const SAND_ORIGIN: Coordinate = Point2::new(500, 0);
+
+/// Indexed by `Tile`.
+const PALETTE: &[Rgb] = &[[24, 20, 37], [110, 110, 120], [232, 193, 112]];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Air,
+    Rock,
+    Sand
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tile::Air => write!(f, "."),
+            Tile::Rock => write!(f, "#"),
+            Tile::Sand => write!(f, "o")
+        }
+    }
+}
+
+/// Positions are grid positions, shifted right of the puzzle's so the pile fits left of the sand origin.
+#[derive(Clone)]
+pub struct Cave {
+    tiles: Grid<Tile>,
+    origin: Coordinate,
+    y_max: usize,
+    floor: Option<usize>,
+    /// Where the last unit of sand fell through, so the next one can start from just above where it rested.
+    path: Vec<Coordinate>
+}
+
+impl Cave {
+    /// Sizes the cave so that sand resting on the floor two rows below the lowest rock still fits:
+    /// the pile spreads at most one column either way per row it falls.
+    fn new(paths: &[Vec<Coordinate>]) -> Self {
+        let bounds = BoundingBox::from_points(paths.iter().flatten().copied().chain([SAND_ORIGIN]))
+            .expect("Should contain the sand origin");
+        let y_max = bounds.max.y;
+        let height = y_max + 3;
+        // Puzzle column `x` is grid column `x + shift`, leaving `height + 1` free columns either side of the origin.
+        let shift = (height + 1).saturating_sub(bounds.min.x);
+        let width = bounds.max.x.max(SAND_ORIGIN.x + height + 1) + shift + 1;
+        let origin = Point2::new(SAND_ORIGIN.x + shift, SAND_ORIGIN.y);
+
+        let mut cave = Cave { tiles: Grid::new(width, height, Tile::Air), origin, y_max, floor: None, path: vec![] };
+        for path in paths {
+            cave.add_rocks(path, shift);
+        }
+
+        cave
+    }
+
+    fn add_rocks(&mut self, coordinates: &[Coordinate], shift: usize) {
+        for (curr, next) in coordinates.iter().tuple_windows() {
+            for x in usize::min(curr.x, next.x)..=usize::max(curr.x, next.x) {
+                for y in usize::min(curr.y, next.y)..=usize::max(curr.y, next.y) {
+                    self.tiles[Point2::new(x + shift, y)] = Tile::Rock;
+                }
+            }
+        }
+    }
+
+    fn set_floor(&mut self) {
+        self.floor = Some(self.y_max + 2);
+    }
+
+    fn drop_sand(&mut self) -> bool {
+        let mut current_pos = self.path.pop().unwrap_or(self.origin);
+
+        while let Some(pos) = self.get_next_fall_position(current_pos) {
+            if self.floor.is_none() && self.is_out_of_bounds(pos.y) {
+                return false
+            }
+            self.path.push(current_pos);
+            current_pos = pos;
+        }
+
+        if current_pos == self.origin {
+            return false;
+        }
+
+        self.tiles[current_pos] = Tile::Sand;
+        viz::frame(|| self.frame());
+
+        true
+    }
+
+    fn frame(&self) -> Frame {
+        Frame::from_grid(&self.tiles, PALETTE, |&tile| tile as u8)
+    }
+
+    fn get_next_fall_position(&self, from: Coordinate) -> Option<Coordinate> {
+        if self.floor == Some(from.y + 1) {
+            return None;
+        }
+
+        [Some(from.x), from.x.checked_sub(1), Some(from.x + 1)].into_iter()
+            .flatten()
+            .map(|x| Point2::new(x, from.y + 1))
+            .find(|&c| self.tiles[c] == Tile::Air)
+    }
+
+    fn is_out_of_bounds(&self, y: usize) -> bool {
+        y >= self.y_max
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = Cave;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let paths = parse::lines(input, |line| line.split("->")
+            .map(|p| coordinate(line, p.trim()))
+            .collect())?;
+
+        Ok(Cave::new(&paths))
+    }
+
+    fn part_1(cave: &Self::Parsed) -> Answer {
+        let mut cave = cave.clone();
+
+        let mut units_at_rest: u32 = 0;
+        while cave.drop_sand() {
+            units_at_rest += 1;
+        }
+        viz::last_frame(|| cave.frame());
+
+        units_at_rest.into()
+    }
+
+    fn part_2(cave: &Self::Parsed) -> Answer {
+        let mut cave = cave.clone();
+        cave.set_floor();
+
+        let mut units_at_rest: u32 = 0;
+        while cave.drop_sand() {
+            units_at_rest += 1;
+        }
+        viz::last_frame(|| cave.frame());
+
+        (units_at_rest + 1).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day14 in 2022;
+        part_1: "day14-1", part 1 => 24;
+        part_2: "day14-1", part 2 => 93;
+    }
+
+    #[test]
+    fn piles_sand_left_of_the_rocks() {
+        let cave = Day14::parse("499,510 -> 501,510").unwrap();
+
+        assert_eq!(Day14::part_1(&cave), Answer::from(1));
+        // A full pile down to the floor at y = 512 holds 512 * 512 units, less the three rocks
+        // and the tile they shelter.
+        assert_eq!(Day14::part_2(&cave), Answer::from(512 * 512 - 4));
+    }
+}
@@ -0,0 +1,97 @@
+use std::ops::RangeInclusive;
+use itertools::Itertools;
+
+use crate::{geometry::Point2, intervals::IntervalSet, parse::{self, ParseError}, solution::{Answer, Solution}};
+
+pub struct Sensor {
+    coordinates: Point2<i32>,
+    beacon: Point2<i32>
+}
+
+impl Sensor {
+    fn parse(l: &str) -> Result<Self, ParseError> {
+        let parts = l.split(&['=', ',', ':'][..]).collect_vec();
+        let coordinate = |i| parse::number(l, parse::token(l, &parts, i, "a coordinate")?, "a coordinate");
+
+        Ok(Sensor {
+            coordinates: Point2::new(coordinate(1)?, coordinate(3)?),
+            beacon: Point2::new(coordinate(5)?, coordinate(7)?)
+        })
+    }
+
+    /// The x values this sensor rules out in row `y`.
+    fn scanned_x_range_at_y(&self, y: i32) -> RangeInclusive<i32> {
+        let reach = self.coordinates.manhattan(self.beacon) - (self.coordinates.y - y).abs();
+
+        self.coordinates.x - reach..=self.coordinates.x + reach
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed = Vec<Sensor>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse::lines(input, Sensor::parse)
+    }
+
+    fn part_1(sensors: &Self::Parsed) -> Answer {
+        covered_in_row(sensors, ROW).into()
+    }
+
+    fn part_2(sensors: &Self::Parsed) -> Answer {
+        tuning_frequency(sensors, LIMIT).into()
+    }
+}
+
+const ROW: i32 = 2000000;
+const LIMIT: i32 = 4000000;
+
+fn scanned_in_row(sensors: &[Sensor], y: i32) -> IntervalSet<i32> {
+    sensors.iter().map(|s| s.scanned_x_range_at_y(y)).collect()
+}
+
+/// Positions in row `y` where no beacon can be; beacons already known in that row don't count.
+fn covered_in_row(sensors: &[Sensor], y: i32) -> i32 {
+    let scanned = scanned_in_row(sensors, y);
+    let beacons = sensors.iter()
+        .map(|s| s.beacon)
+        .filter(|b| b.y == y && scanned.contains(b.x))
+        .unique()
+        .count();
+
+    scanned.len() - beacons as i32
+}
+
+/// Searches `0..=limit` in both directions for the one position no sensor covers.
+fn tuning_frequency(sensors: &[Sensor], limit: i32) -> u64 {
+    let (x, y) = (0..=limit)
+        .find_map(|y| scanned_in_row(sensors, y).complement(0..=limit)
+            .iter()
+            .next()
+            .map(|gap| (*gap.start(), y)))
+        .expect("Should be one position no sensor covers");
+
+    x as u64 * 4000000 + y as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn part_1() {
+        let sensors = parse_example::<Day15>(2022, "day15-1");
+
+        assert_eq!(covered_in_row(&sensors, 10), 26);
+    }
+
+    #[test]
+    fn part_2() {
+        let sensors = parse_example::<Day15>(2022, "day15-1");
+
+        assert_eq!(tuning_frequency(&sensors, 20), 56000011);
+    }
+}
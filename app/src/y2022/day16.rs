@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use itertools::Itertools;
+
+use crate::{parse::{self, ParseError}, solution::{Answer, Solution}};
+
+const START: &str = "AA";
+/// The per-set table in the search has `2^n` entries, so keep `n` sensible.
+const MAX_USEFUL_VALVES: usize = 20;
+/// Large enough to never be worth walking, small enough that adding two never overflows.
+const UNREACHABLE: u32 = u32::MAX / 4;
+
+struct Valve<'a> {
+    name: &'a str,
+    flow: u32,
+    tunnels: Vec<&'a str>
+}
+
+impl<'a> Valve<'a> {
+    fn from_line(line: &'a str) -> Result<Valve<'a>, ParseError> {
+        let parts = line.split_whitespace().collect_vec();
+        let name = parse::token(line, &parts, 1, "a valve name")?;
+        let rate = parse::token(line, &parts, 4, "a flow rate")?;
+        let flow = rate.strip_prefix("rate=")
+            .and_then(|r| r.strip_suffix(';'))
+            .ok_or_else(|| ParseError::at(line, rate, "rate=<flow>;"))?;
+        let flow = parse::number(line, flow, "a flow rate")?;
+        parse::token(line, &parts, 9, "a tunnel")?;
+        let tunnels = parts[9..].iter().map(|t| t.trim_end_matches(',')).collect();
+
+        Ok(Valve { name, flow, tunnels })
+    }
+}
+
+/// The valves worth opening and the minutes it takes to walk between them.
+#[derive(Debug)]
+pub struct Network {
+    flows: Vec<u32>,
+    /// Between every pair of useful valves, and from the start, which has the last index.
+    distances: Vec<Vec<u32>>
+}
+
+impl Network {
+    fn new(valves: &[Valve], tunnels: &[Vec<usize>], start: usize) -> Network {
+        let n = valves.len();
+        let mut all = vec![vec![UNREACHABLE; n]; n];
+        for (i, targets) in tunnels.iter().enumerate() {
+            all[i][i] = 0;
+            for &j in targets {
+                all[i][j] = 1;
+            }
+        }
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    all[i][j] = all[i][j].min(all[i][k] + all[k][j]);
+                }
+            }
+        }
+
+        let useful = (0..n).filter(|&i| valves[i].flow > 0).chain([start]).collect_vec();
+        Network {
+            flows: useful[..useful.len() - 1].iter().map(|&i| valves[i].flow).collect(),
+            distances: useful.iter().map(|&i| useful.iter().map(|&j| all[i][j]).collect()).collect()
+        }
+    }
+
+    /// The most pressure one actor can release in `minutes`, for every set of opened valves
+    /// as a bitmask. Sets that cannot be opened in time stay at zero.
+    fn best_by_opened(&self, minutes: u32) -> Vec<u32> {
+        let mut best = vec![0; 1 << self.flows.len()];
+        self.explore(self.flows.len(), minutes, 0, 0, &mut best);
+        best
+    }
+
+    fn explore(&self, at: usize, minutes: u32, opened: usize, released: u32, best: &mut [u32]) {
+        best[opened] = best[opened].max(released);
+        for (next, &flow) in self.flows.iter().enumerate() {
+            let cost = self.distances[at][next] + 1;
+            if opened & 1 << next == 0 && cost < minutes {
+                let left = minutes - cost;
+                self.explore(next, left, opened | 1 << next, released + flow * left, best);
+            }
+        }
+    }
+
+    fn most_pressure(&self, minutes: u32) -> u32 {
+        self.best_by_opened(minutes).into_iter().max().unwrap_or(0)
+    }
+
+    /// Two actors never need to open the same valve, so the best pair splits the valves
+    /// between them.
+    fn most_pressure_with_help(&self, minutes: u32) -> u32 {
+        let mut best = self.best_by_opened(minutes);
+        // Make `best[set]` the most released opening any subset of `set`.
+        for bit in 0..self.flows.len() {
+            for set in 0..best.len() {
+                if set & 1 << bit != 0 {
+                    best[set] = best[set].max(best[set ^ 1 << bit]);
+                }
+            }
+        }
+
+        let all = best.len() - 1;
+        (0..best.len()).map(|set| best[set] + best[all ^ set]).max().unwrap_or(0)
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed = Network;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let valves = parse::lines(input, Valve::from_line)?;
+        let index: HashMap<&str, usize> = valves.iter().enumerate().map(|(i, v)| (v.name, i)).collect();
+        let start = *index.get(START).ok_or_else(|| ParseError::new(format!("a valve named {}", START)))?;
+
+        let tunnels = valves.iter().zip(input.lines()).enumerate()
+            .map(|(i, (valve, line))| valve.tunnels.iter()
+                .map(|&t| index.get(t).copied().ok_or_else(|| ParseError::at(line, t, "a known valve").at_line(i + 1)))
+                .collect::<Result<Vec<_>, _>>())
+            .collect::<Result<Vec<_>, _>>()?;
+        if valves.iter().filter(|v| v.flow > 0).count() > MAX_USEFUL_VALVES {
+            return Err(ParseError::new(format!("at most {} valves with a positive flow rate", MAX_USEFUL_VALVES)));
+        }
+
+        Ok(Network::new(&valves, &tunnels, start))
+    }
+
+    fn part_1(network: &Self::Parsed) -> Answer {
+        network.most_pressure(30).into()
+    }
+
+    fn part_2(network: &Self::Parsed) -> Answer {
+        network.most_pressure_with_help(26).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day16 in 2022;
+        part_1: "day16-1", part 1 => 1651;
+        part_2: "day16-1", part 2 => 1707;
+    }
+
+    #[test]
+    fn compresses_to_useful_valves() {
+        let network = crate::solution::parse_example::<Day16>(2022, "day16-1");
+
+        assert_eq!(network.flows, vec![13, 2, 20, 3, 22, 21]);
+        // From AA: BB is adjacent, HH is five tunnels away via DD, EE, FF and GG.
+        assert_eq!(network.distances[6][0], 1);
+        assert_eq!(network.distances[6][4], 5);
+    }
+
+    #[test]
+    fn rejects_unknown_tunnels() {
+        let error = Day16::parse("Valve AA has flow rate=0; tunnels lead to valves BB, CC\nValve BB has flow rate=1; tunnel leads to valve AA").unwrap_err();
+
+        assert_eq!(error.to_string(), "line 1, column 54, expected a known valve");
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use crate::{parse::ParseError, solution::{Answer, Solution}};
+
+const WIDTH: usize = 7;
+/// Bit for the leftmost column of a row; column `x` is `LEFT >> x`.
+const LEFT: u8 = 1 << (WIDTH - 1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Push {
+    Left,
+    Right
+}
+
+struct Rock {
+    /// Bottom row first, pushed against the left wall.
+    rows: &'static [u8],
+    width: usize
+}
+
+const ROCKS: [Rock; 5] = [
+    Rock { rows: &[0b1111000], width: 4 },
+    Rock { rows: &[0b0100000, 0b1110000, 0b0100000], width: 3 },
+    Rock { rows: &[0b1110000, 0b0010000, 0b0010000], width: 3 },
+    Rock { rows: &[0b1000000, 0b1000000, 0b1000000, 0b1000000], width: 1 },
+    Rock { rows: &[0b1100000, 0b1100000], width: 2 }
+];
+
+/// How far below the top a column's highest block is looked for. Deeper blocks, or a column
+/// with none, count as this deep, so the surface can repeat even if a column never fills.
+const DEPTH: usize = 64;
+
+/// Next rock, next jet, and how far below the top each column's highest block is, up to `DEPTH`.
+type State = (usize, usize, [usize; WIDTH]);
+
+struct Tower<'a> {
+    jets: &'a [Push],
+    /// One bit per cell, bottom row first. The top row is never empty.
+    rows: Vec<u8>,
+    rocks: usize,
+    jet: usize
+}
+
+impl<'a> Tower<'a> {
+    fn new(jets: &'a [Push]) -> Tower<'a> {
+        Tower { jets, rows: vec![], rocks: 0, jet: 0 }
+    }
+
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn fits(&self, rock: &Rock, x: usize, y: usize) -> bool {
+        rock.rows.iter().enumerate().all(|(k, &row)| self.rows.get(y + k).is_none_or(|&filled| filled & (row >> x) == 0))
+    }
+
+    fn drop_rock(&mut self) {
+        let rock = &ROCKS[self.rocks % ROCKS.len()];
+        let (mut x, mut y) = (2_usize, self.height() + 3);
+        loop {
+            let pushed = match self.jets[self.jet] {
+                Push::Left => x.checked_sub(1),
+                Push::Right => Some(x + 1).filter(|&x| x + rock.width <= WIDTH)
+            };
+            self.jet = (self.jet + 1) % self.jets.len();
+            if let Some(pushed) = pushed.filter(|&p| self.fits(rock, p, y)) {
+                x = pushed;
+            }
+            if y == 0 || !self.fits(rock, x, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+
+        for (k, &row) in rock.rows.iter().enumerate() {
+            if self.rows.len() == y + k {
+                self.rows.push(0);
+            }
+            self.rows[y + k] |= row >> x;
+        }
+        self.rocks += 1;
+    }
+
+    fn state(&self) -> State {
+        let mut profile = [DEPTH; WIDTH];
+        for (depth, &row) in self.rows.iter().rev().take(DEPTH).enumerate() {
+            for (x, column) in profile.iter_mut().enumerate() {
+                if row & (LEFT >> x) != 0 {
+                    *column = (*column).min(depth);
+                }
+            }
+            if profile.iter().all(|&d| d <= depth) {
+                break;
+            }
+        }
+
+        (self.rocks % ROCKS.len(), self.jet, profile)
+    }
+}
+
+/// The height of the tower once `rocks` rocks have come to rest. Once the falling rock, the jet
+/// and the surface repeat, so does the growth of the tower, so the rest is skipped in whole cycles.
+fn height_after(jets: &[Push], rocks: u64) -> u64 {
+    let mut tower = Tower::new(jets);
+    let mut heights = vec![0];
+    let mut seen: HashMap<State, usize> = HashMap::new();
+
+    while (tower.rocks as u64) < rocks {
+        if let Some(start) = seen.insert(tower.state(), tower.rocks) {
+            let (start, now) = (start as u64, tower.rocks as u64);
+            let growth = heights[now as usize] - heights[start as usize];
+            let (cycles, rest) = ((rocks - now) / (now - start), (rocks - now) % (now - start));
+            return heights[now as usize] + cycles * growth + heights[(start + rest) as usize] - heights[start as usize];
+        }
+        tower.drop_rock();
+        heights.push(tower.height() as u64);
+    }
+
+    heights[rocks as usize]
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed = Vec<Push>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let line = input.trim_end();
+        if line.is_empty() {
+            return Err(ParseError::new("a jet pattern").at_line(1));
+        }
+
+        line.char_indices()
+            .map(|(i, c)| match c {
+                '<' => Ok(Push::Left),
+                '>' => Ok(Push::Right),
+                _ => Err(ParseError::at(line, &line[i..i + c.len_utf8()], "< or >").at_line(1))
+            })
+            .collect()
+    }
+
+    fn part_1(jets: &Self::Parsed) -> Answer {
+        height_after(jets, 2022).into()
+    }
+
+    fn part_2(jets: &Self::Parsed) -> Answer {
+        height_after(jets, 1_000_000_000_000).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day17 in 2022;
+        part_1: "day17-1", part 1 => 3068;
+        part_2: "day17-1", part 2 => 1514285714288_i64;
+    }
+
+    #[test]
+    fn height_after_a_few_rocks() {
+        let jets = crate::solution::parse_example::<Day17>(2022, "day17-1");
+
+        assert_eq!(height_after(&jets, 0), 0);
+        assert_eq!(height_after(&jets, 1), 1);
+        assert_eq!(height_after(&jets, 2), 4);
+        assert_eq!(height_after(&jets, 10), 17);
+    }
+
+    #[test]
+    fn repeats_with_a_column_that_never_fills() {
+        let jets = Day17::parse("<").unwrap();
+        let mut tower = Tower::new(&jets);
+        for _ in 0..5000 {
+            tower.drop_rock();
+        }
+
+        assert_eq!(tower.rows.iter().fold(0, |filled, &row| filled | row) & 1, 0);
+        assert_eq!(height_after(&jets, 5000), tower.height() as u64);
+        // Every round of the five rocks against the left wall adds 11 rows.
+        assert_eq!(height_after(&jets, 1_000_000_000_000), 2_200_000_000_000);
+    }
+}
@@ -0,0 +1,42 @@
+use itertools::Itertools;
+
+use crate::{geometry::Point3, parse::{self, ParseError}, solution::{Answer, Solution}, voxels::VoxelSet};
+
+fn cube_from_str(s: &str) -> Result<Point3<i32>, ParseError> {
+    let parts = s.split(',').collect_vec();
+    if parts.len() > 3 {
+        return Err(ParseError::at(s, parts[3], "the end of the line"));
+    }
+    let coordinate = |i| parse::number(s, parse::token(s, &parts, i, "a coordinate")?, "a coordinate");
+
+    Ok(Point3::new(coordinate(0)?, coordinate(1)?, coordinate(2)?))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed = VoxelSet<i32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(parse::lines(input, cube_from_str)?.into_iter().collect())
+    }
+
+    fn part_1(droplet: &Self::Parsed) -> Answer {
+        droplet.surface_area().into()
+    }
+
+    fn part_2(droplet: &Self::Parsed) -> Answer {
+        droplet.exterior_surface_area().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day18 in 2022;
+        part_1: "day18-1", part 1 => 64;
+        part_2: "day18-1", part 2 => 58;
+    }
+}
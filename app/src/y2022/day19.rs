@@ -0,0 +1,157 @@
+use std::thread;
+use itertools::Itertools;
+
+use crate::{parse::{self, ParseError}, solution::{Answer, Solution}};
+
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+pub struct Blueprint {
+    id: u32,
+    /// Ore, clay and obsidian needed for each kind of robot, indexed like the resources.
+    costs: [[u32; 3]; 4],
+    /// More robots of a kind than the most any robot costs of that resource can never help,
+    /// since only one robot is built per minute.
+    caps: [u32; 3]
+}
+
+impl Blueprint {
+    fn from_str(s: &str) -> Result<Blueprint, ParseError> {
+        let parts = s.split_whitespace().collect_vec();
+        let number = |i, expected| parse::number(s, parse::token(s, &parts, i, expected)?.trim_end_matches(':'), expected);
+        let id = number(1, "a blueprint id")?;
+        let mut costs = [[0; 3]; 4];
+        costs[ORE][ORE] = number(6, "an ore cost")?;
+        costs[CLAY][ORE] = number(12, "an ore cost")?;
+        costs[OBSIDIAN][ORE] = number(18, "an ore cost")?;
+        costs[OBSIDIAN][CLAY] = number(21, "a clay cost")?;
+        costs[GEODE][ORE] = number(27, "an ore cost")?;
+        costs[GEODE][OBSIDIAN] = number(30, "an obsidian cost")?;
+
+        let caps = [ORE, CLAY, OBSIDIAN].map(|resource| costs.iter().map(|c| c[resource]).max().unwrap_or(0));
+        Ok(Blueprint { id, costs, caps })
+    }
+
+    /// The most geodes that can be opened in `minutes`.
+    fn max_geodes(&self, minutes: u32) -> u32 {
+        let mut best = 0;
+        self.search(State { minutes, robots: [1, 0, 0], stock: [0; 3], geodes: 0 }, &mut best);
+        best
+    }
+
+    /// Branches on which robot to build next, waiting as long as it takes to afford it.
+    fn search(&self, state: State, best: &mut u32) {
+        *best = (*best).max(state.geodes);
+        // Even a new geode robot every remaining minute cannot beat the best found so far.
+        if state.geodes + state.minutes.saturating_sub(1) * state.minutes / 2 <= *best {
+            return;
+        }
+
+        for robot in [GEODE, OBSIDIAN, CLAY, ORE] {
+            if robot != GEODE && state.robots[robot] >= self.caps[robot] {
+                continue;
+            }
+            let Some(wait) = state.wait_for(&self.costs[robot]) else { continue };
+            // The robot has to be running for at least a minute to be worth building.
+            if wait + 1 >= state.minutes {
+                continue;
+            }
+
+            let minutes = state.minutes - wait - 1;
+            let mut next = State { minutes, ..state };
+            for resource in [ORE, CLAY, OBSIDIAN] {
+                next.stock[resource] = state.stock[resource] + state.robots[resource] * (wait + 1) - self.costs[robot][resource];
+            }
+            match robot {
+                GEODE => next.geodes += minutes,
+                _ => next.robots[robot] += 1
+            }
+            self.search(next, best);
+        }
+    }
+}
+
+/// Geodes are counted for the whole run as soon as their robot is built.
+#[derive(Debug, Clone, Copy)]
+struct State {
+    minutes: u32,
+    robots: [u32; 3],
+    stock: [u32; 3],
+    geodes: u32
+}
+
+impl State {
+    /// Minutes of collecting before `cost` can be paid, or `None` if no robot collects a resource it needs.
+    fn wait_for(&self, cost: &[u32; 3]) -> Option<u32> {
+        (0..3)
+            .map(|r| match (cost[r].saturating_sub(self.stock[r]), self.robots[r]) {
+                (0, _) => Some(0),
+                (_, 0) => None,
+                (missing, robots) => Some(missing.div_ceil(robots))
+            })
+            .try_fold(0, |wait, w| Some(wait.max(w?)))
+    }
+}
+
+/// Evaluates every blueprint on a thread of its own.
+fn max_geodes(blueprints: &[Blueprint], minutes: u32) -> Vec<u32> {
+    thread::scope(|scope| {
+        let handles = blueprints.iter().map(|b| scope.spawn(move || b.max_geodes(minutes))).collect_vec();
+        handles.into_iter().map(|h| h.join().expect("Should not panic")).collect()
+    })
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse::lines(input, Blueprint::from_str)
+    }
+
+    fn part_1(blueprints: &Self::Parsed) -> Answer {
+        max_geodes(blueprints, 24).iter()
+            .zip(blueprints)
+            .map(|(geodes, b)| geodes * b.id)
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part_2(blueprints: &Self::Parsed) -> Answer {
+        if blueprints.is_empty() {
+            return 0.into();
+        }
+        let first = &blueprints[..blueprints.len().min(3)];
+        max_geodes(first, 32).iter().map(|&g| g as u64).product::<u64>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day19 in 2022;
+        part_1: "day19-1", part 1 => 33;
+        part_2: "day19-1", part 2 => 56 * 62;
+    }
+
+    #[test]
+    fn geodes_per_blueprint() {
+        let blueprints = crate::solution::parse_example::<Day19>(2022, "day19-1");
+
+        assert_eq!(blueprints[1].costs[GEODE], [3, 0, 12]);
+        assert_eq!(max_geodes(&blueprints, 24), vec![9, 12]);
+    }
+
+    #[test]
+    fn no_blueprints_open_no_geodes() {
+        let blueprints = Day19::parse("").unwrap();
+
+        assert_eq!(Day19::part_1(&blueprints), Answer::from(0));
+        assert_eq!(Day19::part_2(&blueprints), Answer::from(0));
+    }
+}
@@ -0,0 +1,87 @@
+use itertools::Itertools;
+
+use crate::{parse::{self, ParseError}, solution::{Answer, Solution}};
+
+struct FilePart {
+    original: usize,
+    current: usize,
+    value: i64
+}
+
+impl FilePart {
+    fn new(original: usize, current: usize, value: i64) -> Self {
+        FilePart { original, current, value }
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse::lines(input, |l| parse::number(l, l.trim(), "a number"))
+    }
+
+    fn part_1(values: &Self::Parsed) -> Answer {
+        let mut file: Vec<FilePart> = values.iter()
+            .enumerate()
+            .map(|(i, &value)| FilePart::new(i, i, value))
+            .collect_vec();
+
+        for i in 0..file.len() {
+            mix(&mut file, i);
+        }
+
+        grove_coordinates(&file).into()
+    }
+
+    fn part_2(values: &Self::Parsed) -> Answer {
+        let mut file: Vec<FilePart> = values.iter()
+            .enumerate()
+            .map(|(i, &value)| FilePart::new(i, i, value * 811589153))
+            .collect_vec();
+
+        for _ in 0..10 {
+            for i in 0..file.len() {
+                mix(&mut file, i);
+            }
+        }
+
+        grove_coordinates(&file).into()
+    }
+}
+
+fn grove_coordinates(file: &[FilePart]) -> i64 {
+    let val_0_index = file.iter().find(|fp| fp.value == 0).unwrap().current;
+
+    (file[(val_0_index + 1000) % file.len()].value) +
+        (file[(val_0_index + 2000) % file.len()].value) +
+        (file[(val_0_index + 3000) % file.len()].value)
+}
+
+fn mix(file: &mut Vec<FilePart>, i: usize) {
+    let len = file.len();
+    let item = file.remove(file.iter().find(|f| f.original == i).unwrap().current);
+
+    let mut _insert_at = (item.current as i64 + item.value) % (len as i64 - 1);
+    if _insert_at < 0 {
+        _insert_at += len as i64 - 1;
+    }
+    file.insert(_insert_at as usize, item);
+
+    for (i, part) in file.iter_mut().enumerate() {
+        part.current = i;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day20 in 2022;
+        part_1: "day20-1", part 1 => 3;
+        part_2: "day20-1", part 2 => 1623178306;
+    }
+}
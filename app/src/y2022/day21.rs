@@ -0,0 +1,233 @@
+use std::collections::{HashMap, HashSet};
+use itertools::Itertools;
+
+use crate::{parse::{self, ParseError}, solution::{Answer, Solution}};
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    name: String,
+    depends_on: Option<(String, String)>,
+    message: Message
+}
+
+impl Monkey {
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let ch: &[_] = &[':', ' '];
+        let parts = s.split(ch).collect_vec();
+        let name = parse::token(s, &parts, 0, "a monkey name")?.to_string();
+        if parts.len() <= 3 {
+            Ok(Monkey {
+                name,
+                depends_on: None,
+                message: Message::Integer(parse::number(s, parse::token(s, &parts, 2, "a number")?, "a number")?)
+            })
+        } else {
+            Ok(Monkey {
+                name,
+                depends_on: Some((
+                 parse::token(s, &parts, 2, "a monkey name")?.to_string(),
+                 parse::token(s, &parts, 4, "a monkey name")?.to_string()
+                )),
+                message: Message::operation(s, parts[3])?
+            })
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Integer(i64),
+    Add,
+    Subtract,
+    Multiply,
+    Divide
+}
+
+impl Message {
+    fn operation(line: &str, operator: &str) -> Result<Self, ParseError> {
+        match operator {
+            "+" => Ok(Message::Add),
+            "-" => Ok(Message::Subtract),
+            "*" => Ok(Message::Multiply),
+            "/" => Ok(Message::Divide),
+            _ => Err(ParseError::at(line, operator, "one of +, -, * or /"))
+        }
+    }
+
+    fn as_integer(&self) -> Option<i64> {
+        match self {
+            Message::Integer(i) => Some(*i),
+            _ => None
+        }
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Parsed = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let monkeys = parse::lines(input, Monkey::from_str)?;
+
+        let names: HashSet<&str> = monkeys.iter().map(|m| m.name.as_str()).collect();
+        for (i, (line, monkey)) in input.lines().zip(&monkeys).enumerate() {
+            let Some((a, b)) = &monkey.depends_on else { continue };
+            if let Some(missing) = [a, b].into_iter().find(|name| !names.contains(name.as_str())) {
+                let token = line.split(' ').find(|t| t == missing).unwrap_or(line);
+                return Err(ParseError::at(line, token, "the name of a monkey in the list").at_line(i + 1));
+            }
+        }
+        if !names.contains("root") {
+            return Err(ParseError::new("a monkey named root"));
+        }
+
+        Ok(monkeys)
+    }
+
+    fn part_1(monkey_queue: &Self::Parsed) -> Answer {
+        let mut known: HashMap<&str, i64> = HashMap::new();
+        let mut i = 0;
+        while known.len() < monkey_queue.len() {
+            let m = &monkey_queue[i];
+            if m.depends_on.is_none() {
+                known.insert(&m.name, m.message.as_integer().unwrap());
+            } else if let Some((dep_a, dep_b)) = &m.depends_on {
+                if known.contains_key(dep_a.as_str()) && known.contains_key(dep_b.as_str()) {
+                    let dep_a = known.get(dep_a.as_str()).unwrap();
+                    let dep_b = known.get(dep_b.as_str()).unwrap();
+                    let int = match m.message {
+                        Message::Add => dep_a + dep_b,
+                        Message::Subtract => dep_a - dep_b,
+                        Message::Multiply => dep_a * dep_b,
+                        Message::Divide => dep_a / dep_b,
+                        _ => panic!()
+                    };
+                    known.insert(&m.name, int);
+                }
+            }
+
+            if i == monkey_queue.len() - 1 {
+                i = 0;
+            } else {
+                i += 1;
+            }
+        }
+
+        (*known.get("root").unwrap()).into()
+    }
+
+    fn part_2(monkey_queue: &Self::Parsed) -> Answer {
+        let correct_assignments = monkey_queue.iter()
+            .filter(|m| m.name != "root" && m.name != "humn")
+            .collect_vec();
+
+        let mut known_numbers: HashMap<&str, i64> = HashMap::new();
+        loop {
+            let mut new_known = 0;
+            for m in correct_assignments.iter() {
+                if m.depends_on.is_none() {
+                    if known_numbers.insert(&m.name, m.message.as_integer().unwrap()).is_none() {
+                        new_known += 1;
+                    }
+                } else if let Some((dep_a, dep_b)) = &m.depends_on {
+                    if known_numbers.contains_key(dep_a.as_str()) && known_numbers.contains_key(dep_b.as_str()) {
+                        let dep_a = known_numbers.get(dep_a.as_str()).unwrap();
+                        let dep_b = known_numbers.get(dep_b.as_str()).unwrap();
+                        let int = match m.message {
+                            Message::Add => dep_a + dep_b,
+                            Message::Subtract => dep_a - dep_b,
+                            Message::Multiply => dep_a * dep_b,
+                            Message::Divide => dep_a / dep_b,
+                            _ => panic!()
+                        };
+                        if known_numbers.insert(&m.name, int).is_none() {
+                            new_known += 1;
+                        }
+                    }
+                }
+            }
+
+            if new_known == 0 {
+                break;
+            }
+        }
+
+        let root = monkey_queue.iter().find(|m| m.name == "root").unwrap();
+        let (root_a, root_b) = root.depends_on.as_ref().unwrap();
+        let root_known_part = known_numbers.iter()
+            .filter(|p| p.0 == root_a || p.0 == root_b)
+            .last().unwrap();
+
+        let mut _unknown_part = "";
+        if root_known_part.0 == root_a {
+            _unknown_part = root_b;
+        } else {
+            _unknown_part = root_a;
+        }
+
+        let mut should_say = *known_numbers.iter().find(|m| m.0 == root_known_part.0).unwrap().1;
+        loop {
+            let m = monkey_queue.iter().find(|m| m.name == _unknown_part).unwrap();
+            if let Some((a, b)) = &m.depends_on {
+                let mut backwards = false;
+                let mut unknown = a;
+                let mut known = b;
+                if known_numbers.contains_key(a.as_str()) {
+                    unknown = b;
+                    known = a;
+                    backwards = true;
+                }
+                let known = known_numbers.iter().find(|m| m.0 == known).unwrap();
+                let unknown = monkey_queue.iter().find(|m| &m.name == unknown).unwrap();
+                should_say = match m.message {
+                    Message::Add => should_say - known.1,
+                    Message::Subtract => {
+                        if backwards {
+                            known.1 - should_say
+                        } else {
+                            should_say + known.1
+                        }
+                    },
+                    Message::Multiply => should_say / known.1,
+                    Message::Divide => {
+                        if backwards {
+                            known.1 / should_say
+                        } else {
+                            should_say * known.1
+                        }
+                    },
+                    _ => panic!()
+                };
+
+                known_numbers.insert(&unknown.name, should_say);
+                _unknown_part = &unknown.name;
+            }
+
+            if _unknown_part == "humn" {
+                break;
+            }
+        }
+
+        (*known_numbers.get("humn").unwrap()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day21 in 2022;
+        part_1: "day21-1", part 1 => 152;
+        part_2: "day21-1", part 2 => 301;
+    }
+
+    #[test]
+    fn rejects_unknown_monkeys() {
+        let error = Day21::parse("root: aaaa + bbbb\naaaa: 5").unwrap_err();
+
+        assert_eq!((error.line, error.column), (Some(1), Some(14)));
+        assert!(Day21::parse("aaaa: 5").is_err());
+    }
+}
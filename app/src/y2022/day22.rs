@@ -0,0 +1,305 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{geometry::{Dir4, Point2, Point3}, grid::Grid, parse::{self, ParseError}, solution::{Answer, Solution}};
+
+type Position = Point2<usize>;
+type Vector = Point3<i64>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Void,
+    Open,
+    Wall
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Forward(u32),
+    Left,
+    Right
+}
+
+fn path_from_str(line: &str) -> Result<Vec<Step>, ParseError> {
+    let mut steps = vec![];
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        let length = match c {
+            'L' => {
+                steps.push(Step::Left);
+                1
+            },
+            'R' => {
+                steps.push(Step::Right);
+                1
+            },
+            '0'..='9' => {
+                let length = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+                steps.push(Step::Forward(parse::number(line, &rest[..length], "a number of tiles")?));
+                length
+            },
+            _ => return Err(ParseError::at(line, &rest[..c.len_utf8()], "a number, L or R"))
+        };
+        rest = &rest[length..];
+    }
+
+    Ok(steps)
+}
+
+/// One side of the cube: where it sits in the net, and which way its edges point once folded.
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    corner: Position,
+    normal: Vector,
+    right: Vector,
+    down: Vector
+}
+
+impl Face {
+    fn towards(&self, dir: Dir4) -> Vector {
+        match dir {
+            Dir4::Right => self.right,
+            Dir4::Left => -self.right,
+            Dir4::Down => self.down,
+            Dir4::Up => -self.down
+        }
+    }
+
+    fn direction_of(&self, v: Vector) -> Dir4 {
+        Dir4::ALL.into_iter().find(|&d| self.towards(d) == v).expect("Should be parallel to the face")
+    }
+
+    /// The face next to this one in the net, in direction `dir`, folded over the shared edge.
+    fn fold(&self, dir: Dir4, corner: Position) -> Face {
+        let (right, down) = match dir {
+            Dir4::Right => (-self.normal, self.down),
+            Dir4::Left => (self.normal, self.down),
+            Dir4::Down => (self.right, -self.normal),
+            Dir4::Up => (self.right, self.normal)
+        };
+
+        Face { corner, normal: self.towards(dir), right, down }
+    }
+}
+
+/// The board folded into a cube, worked out from the layout of the net.
+#[derive(Debug)]
+struct Cube {
+    size: usize,
+    faces: Vec<Face>
+}
+
+impl Cube {
+    /// `None` unless the board is six equal squares that fold into a cube.
+    fn fold(board: &Grid<Tile>) -> Option<Cube> {
+        let tiles = board.iter().filter(|&(_, &t)| t != Tile::Void).count();
+        let size = (1..).find(|s| 6 * s * s >= tiles).filter(|s| 6 * s * s == tiles)?;
+        let corners: Vec<Position> = (0..board.height()).step_by(size)
+            .flat_map(|y| (0..board.width()).step_by(size).map(move |x| Position::new(x, y)))
+            .filter(|&p| board[p] != Tile::Void)
+            .collect();
+
+        let first = Face { corner: corners[0], normal: Vector::new(0, 0, -1), right: Vector::new(1, 0, 0), down: Vector::new(0, 1, 0) };
+        let mut faces: HashMap<Position, Face> = HashMap::from([(first.corner, first)]);
+        let mut unfolded = vec![first];
+        while let Some(face) = unfolded.pop() {
+            for dir in Dir4::ALL {
+                let offset = dir.offset::<isize>() * size as isize;
+                let next = corners.iter().find(|c| Some(**c) == offset_position(face.corner, offset));
+                if let Some(&corner) = next.filter(|c| !faces.contains_key(c)) {
+                    let folded = face.fold(dir, corner);
+                    faces.insert(corner, folded);
+                    unfolded.push(folded);
+                }
+            }
+        }
+
+        let faces: Vec<Face> = corners.iter().map(|c| faces.get(c).copied()).collect::<Option<_>>()?;
+        let sides = faces.iter().map(|f| f.normal).collect::<HashSet<_>>();
+        (faces.len() == 6 && sides.len() == 6).then_some(Cube { size, faces })
+    }
+
+    fn face_at(&self, p: Position) -> &Face {
+        self.faces.iter()
+            .find(|f| (f.corner.x..f.corner.x + self.size).contains(&p.x) && (f.corner.y..f.corner.y + self.size).contains(&p.y))
+            .expect("Should be on a face")
+    }
+
+    /// Where walking off the edge of a face leads. Tiles are placed on a cube of side `2 * size`
+    /// centred on the origin, so every tile centre has odd coordinates along its face.
+    fn wrap(&self, p: Position, dir: Dir4) -> (Position, Dir4) {
+        let size = self.size as i64;
+        let from = self.face_at(p);
+        let (i, j) = ((p.x - from.corner.x) as i64, (p.y - from.corner.y) as i64);
+        let centre = from.normal * size + from.right * (2 * i + 1 - size) + from.down * (2 * j + 1 - size);
+        let across = centre + from.towards(dir) - from.normal;
+
+        let to = self.faces.iter().find(|f| f.normal == from.towards(dir)).expect("Should have a face on every side");
+        let (i, j) = ((across.dot(to.right) + size - 1) / 2, (across.dot(to.down) + size - 1) / 2);
+        (Position::new(to.corner.x + i as usize, to.corner.y + j as usize), to.direction_of(-from.normal))
+    }
+}
+
+fn offset_position(p: Position, offset: Point2<isize>) -> Option<Position> {
+    Some(Position::new(p.x.checked_add_signed(offset.x)?, p.y.checked_add_signed(offset.y)?))
+}
+
+pub struct Notes {
+    board: Grid<Tile>,
+    path: Vec<Step>,
+    /// `None` if the board is not a cube net, which only matters for part 2.
+    cube: Option<Cube>
+}
+
+impl Notes {
+    /// The next tile in `dir`, unless that is off the board.
+    fn step(&self, p: Position, dir: Dir4) -> Option<Position> {
+        offset_position(p, dir.offset())
+            .filter(|&q| self.board.get(q.x, q.y).is_some_and(|&t| t != Tile::Void))
+    }
+
+    /// Walking off the board comes back on the far side of the same row or column.
+    fn wrap_flat(&self, p: Position, dir: Dir4) -> (Position, Dir4) {
+        let mut p = p;
+        while let Some(back) = self.step(p, dir.reverse()) {
+            p = back;
+        }
+
+        (p, dir)
+    }
+
+    /// Follows the path from the leftmost open tile of the top row and returns the password.
+    fn walk(&self, wrap: impl Fn(Position, Dir4) -> (Position, Dir4)) -> usize {
+        let start = self.board.row(0).iter().position(|&t| t == Tile::Open).expect("Should have an open tile in the top row");
+        let (mut p, mut dir) = (Position::new(start, 0), Dir4::Right);
+
+        for &step in &self.path {
+            match step {
+                Step::Left => dir = dir.turn_left(),
+                Step::Right => dir = dir.turn_right(),
+                Step::Forward(tiles) => for _ in 0..tiles {
+                    let (next, turned) = self.step(p, dir).map_or_else(|| wrap(p, dir), |q| (q, dir));
+                    if self.board[next] == Tile::Wall {
+                        break;
+                    }
+                    (p, dir) = (next, turned);
+                }
+            }
+        }
+
+        let facing = match dir {
+            Dir4::Right => 0,
+            Dir4::Down => 1,
+            Dir4::Left => 2,
+            Dir4::Up => 3
+        };
+        1000 * (p.y + 1) + 4 * (p.x + 1) + facing
+    }
+}
+
+fn board_from_lines(lines: &[&str]) -> Result<Grid<Tile>, ParseError> {
+    let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+    let mut board = Grid::new(width, lines.len(), Tile::Void);
+    for (y, line) in lines.iter().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            board[(x, y)] = match c {
+                ' ' => Tile::Void,
+                '.' => Tile::Open,
+                '#' => Tile::Wall,
+                _ => return Err(ParseError::new("a space, . or #").at_line(y + 1).at_column(x + 1))
+            };
+        }
+    }
+
+    Ok(board)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Parsed = Notes;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+        let blank = lines.iter().position(|l| l.is_empty()).ok_or_else(|| ParseError::new("a blank line before the path"))?;
+        let board = board_from_lines(&lines[..blank])?;
+        if board.height() == 0 || !board.row(0).contains(&Tile::Open) {
+            return Err(ParseError::new("an open tile in the top row").at_line(1));
+        }
+        let path = lines.get(blank + 1).copied().unwrap_or_default();
+        let path = path_from_str(path.trim_end()).map_err(|e| e.at_line(blank + 2))?;
+        let cube = Cube::fold(&board);
+
+        Ok(Notes { board, path, cube })
+    }
+
+    fn part_1(notes: &Self::Parsed) -> Answer {
+        notes.walk(|p, dir| notes.wrap_flat(p, dir)).into()
+    }
+
+    fn part_2(notes: &Self::Parsed) -> Answer {
+        match &notes.cube {
+            Some(cube) => notes.walk(|p, dir| cube.wrap(p, dir)).into(),
+            None => "the board does not fold into a cube".into()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day22 in 2022;
+        part_1: "day22-1", part 1 => 6032;
+        part_2: "day22-1", part 2 => 5031;
+    }
+
+    #[test]
+    fn parses_path() {
+        assert_eq!(path_from_str("10R5L").unwrap(), vec![Step::Forward(10), Step::Right, Step::Forward(5), Step::Left]);
+        assert_eq!(path_from_str("10X").unwrap_err().column, Some(3));
+    }
+
+    #[test]
+    fn wraps_around_the_example_cube() {
+        let notes = crate::solution::parse_example::<Day22>(2022, "day22-1");
+        let cube = notes.cube.as_ref().unwrap();
+
+        assert_eq!(cube.wrap(Position::new(11, 5), Dir4::Right), (Position::new(14, 8), Dir4::Down));
+        assert_eq!(cube.wrap(Position::new(10, 11), Dir4::Down), (Position::new(1, 7), Dir4::Up));
+        assert_eq!(notes.wrap_flat(Position::new(11, 5), Dir4::Right), (Position::new(0, 5), Dir4::Right));
+    }
+
+    /// Every way off the edge of the net leads back the same way after turning around.
+    fn assert_edges_glue(layout: &str, size: usize) {
+        let map: String = layout.lines()
+            .flat_map(|row| std::iter::repeat_n(row.chars().flat_map(|c| std::iter::repeat_n(if c == '#' { '.' } else { ' ' }, size)).collect::<String>(), size))
+            .map(|row| row + "\n")
+            .collect();
+        let notes = Day22::parse(&format!("{}\n1", map)).unwrap();
+        let cube = notes.cube.as_ref().unwrap();
+
+        for (p, _) in notes.board.iter().filter(|&(_, &t)| t == Tile::Open) {
+            for dir in Dir4::ALL.into_iter().filter(|&d| notes.step(p.into(), d).is_none()) {
+                let (q, arrived) = cube.wrap(p.into(), dir);
+                assert_eq!(cube.wrap(q, arrived.reverse()), (p.into(), dir.reverse()), "{:?} going {:?}", p, dir);
+            }
+        }
+    }
+
+    #[test]
+    fn folds_other_nets() {
+        assert_edges_glue("  #\n###\n  ##", 4);
+        assert_edges_glue(" ##\n #\n##\n#", 5);
+        assert_edges_glue("#\n###\n #\n #", 3);
+    }
+
+    #[test]
+    fn walks_flat_boards_that_are_not_cubes() {
+        let notes = Day22::parse("...#\r\n....\r\n\r\n5R2\r\n").unwrap();
+
+        assert!(notes.cube.is_none());
+        assert_eq!(Day22::part_1(&notes), Answer::from(1013));
+        assert_eq!(Day22::part_2(&notes), Answer::from("the board does not fold into a cube"));
+    }
+}
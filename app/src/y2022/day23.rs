@@ -0,0 +1,109 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{geometry::{BoundingBox, Dir8, Point2}, grid::Grid, parse::ParseError, solution::{Answer, Solution}};
+
+type Elf = Point2<i32>;
+
+/// The directions the elves consider moving in, in the order of the first round.
+const PROPOSALS: [Dir8; 4] = [Dir8::N, Dir8::S, Dir8::W, Dir8::E];
+
+#[derive(Clone)]
+pub struct Grove {
+    elves: FxHashSet<Elf>,
+    rounds: usize
+}
+
+impl Grove {
+    /// Which of the eight surrounding tiles hold an elf, one bit per `Dir8::ALL` index.
+    fn neighbours(&self, elf: Elf) -> u8 {
+        Dir8::ALL.iter().enumerate()
+            .filter(|&(_, &d)| self.elves.contains(&(elf + d.offset())))
+            .fold(0, |bits, (i, _)| bits | 1 << i)
+    }
+
+    /// Where the elf would like to go this round, if anywhere.
+    fn proposal(&self, elf: Elf) -> Option<Elf> {
+        let neighbours = self.neighbours(elf);
+        if neighbours == 0 {
+            return None;
+        }
+
+        (0..PROPOSALS.len())
+            .map(|i| PROPOSALS[(self.rounds + i) % PROPOSALS.len()])
+            .find(|&d| [d.rotate_left(1), d, d.rotate_right(1)].iter().all(|&side| neighbours & 1 << side as usize == 0))
+            .map(|d| elf + d.offset())
+    }
+
+    /// Plays a round and returns how many elves moved.
+    fn round(&mut self) -> usize {
+        // Each target remembers the elf heading there, or `None` once two elves want it.
+        let mut targets: FxHashMap<Elf, Option<Elf>> = FxHashMap::with_capacity_and_hasher(self.elves.len(), Default::default());
+        for &elf in &self.elves {
+            if let Some(target) = self.proposal(elf) {
+                targets.entry(target).and_modify(|from| *from = None).or_insert(Some(elf));
+            }
+        }
+
+        let mut moved = 0;
+        for (target, from) in targets {
+            if let Some(from) = from {
+                self.elves.remove(&from);
+                self.elves.insert(target);
+                moved += 1;
+            }
+        }
+        self.rounds += 1;
+
+        moved
+    }
+
+    fn empty_tiles(&self) -> i32 {
+        BoundingBox::from_points(self.elves.iter().copied()).map_or(0, |b| b.area()) - self.elves.len() as i32
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Parsed = Grove;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let map = Grid::parse(input, "# or .", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None
+        })?;
+        let elves = map.iter().filter(|&(_, &elf)| elf).map(|((x, y), _)| Elf::new(x as i32, y as i32)).collect();
+
+        Ok(Grove { elves, rounds: 0 })
+    }
+
+    fn part_1(grove: &Self::Parsed) -> Answer {
+        let mut grove = grove.clone();
+        for _ in 0..10 {
+            grove.round();
+        }
+
+        grove.empty_tiles().into()
+    }
+
+    fn part_2(grove: &Self::Parsed) -> Answer {
+        let mut grove = grove.clone();
+        while grove.round() > 0 {}
+
+        grove.rounds.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day23 in 2022;
+        part_1: "day23-1", part 1 => 110;
+        part_2: "day23-1", part 2 => 20;
+        part_1_small: "day23-2", part 1 => 25;
+        part_2_small: "day23-2", part 2 => 4;
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{geometry::{Dir4, Point2}, grid::Grid, parse::ParseError, search, solution::{Answer, Solution}};
+
+type Position = Point2<usize>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Wall,
+    Open,
+    Blizzard(Dir4)
+}
+
+pub struct Valley {
+    walls: Grid<bool>,
+    start: Position,
+    goal: Position,
+    /// The blizzards come back to where they started after this many minutes.
+    period: usize,
+    /// Which tiles hold a blizzard, for every minute of the period.
+    blizzards: Vec<Grid<bool>>
+}
+
+impl Valley {
+    fn new(map: &Grid<Tile>, start: Position, goal: Position) -> Valley {
+        let (width, height) = (map.width() - 2, map.height() - 2);
+        let period = width / gcd(width, height) * height;
+        let mut blizzards = vec![Grid::new(map.width(), map.height(), false); period];
+
+        for ((x, y), &tile) in map.iter() {
+            let Tile::Blizzard(dir) = tile else { continue };
+            let offset = dir.offset::<isize>();
+            for (minute, occupied) in blizzards.iter_mut().enumerate() {
+                let moved = |from: usize, by: isize, size: usize| (from - 1 + (by.rem_euclid(size as isize) as usize) * minute) % size + 1;
+                occupied[(moved(x, offset.x, width), moved(y, offset.y, height))] = true;
+            }
+        }
+
+        Valley { walls: map.map(|&t| t == Tile::Wall), start, goal, period, blizzards }
+    }
+
+    /// The first minute at which `to` can be reached when leaving `from` at minute `departure`.
+    fn crossing(&self, from: Position, to: Position, departure: usize) -> usize {
+        let found = search::bfs(
+            (from, departure % self.period),
+            |&(p, minute)| {
+                let next = (minute + 1) % self.period;
+                let moves = Dir4::ALL.into_iter().filter_map(move |d| {
+                    let offset = d.offset::<isize>();
+                    Some(Position::new(p.x.checked_add_signed(offset.x)?, p.y.checked_add_signed(offset.y)?))
+                });
+                std::iter::once(p).chain(moves)
+                    .filter(move |q| self.walls.get(q.x, q.y) == Some(&false) && !self.blizzards[next][*q])
+                    .map(move |q| (q, next))
+                    .collect::<Vec<_>>()
+            },
+            |&(p, _)| p == to
+        );
+
+        departure + found.expect("Should be able to cross the valley").cost
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Parsed = Valley;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let map = Grid::parse(input, "#, ., ^, >, v or <", |c| match c {
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Open),
+            '^' => Some(Tile::Blizzard(Dir4::Up)),
+            '>' => Some(Tile::Blizzard(Dir4::Right)),
+            'v' => Some(Tile::Blizzard(Dir4::Down)),
+            '<' => Some(Tile::Blizzard(Dir4::Left)),
+            _ => None
+        })?;
+        if map.width() < 3 || map.height() < 3 {
+            return Err(ParseError::new("a valley surrounded by walls"));
+        }
+
+        let gap = |y: usize| map.row(y).iter().position(|&t| t == Tile::Open)
+            .map(|x| Position::new(x, y))
+            .ok_or_else(|| ParseError::new("a gap in the wall").at_line(y + 1));
+        let (start, goal) = (gap(0)?, gap(map.height() - 1)?);
+
+        Ok(Valley::new(&map, start, goal))
+    }
+
+    fn part_1(valley: &Self::Parsed) -> Answer {
+        valley.crossing(valley.start, valley.goal, 0).into()
+    }
+
+    fn part_2(valley: &Self::Parsed) -> Answer {
+        let there = valley.crossing(valley.start, valley.goal, 0);
+        let back = valley.crossing(valley.goal, valley.start, there);
+        valley.crossing(valley.start, valley.goal, back).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day24 in 2022;
+        part_1: "day24-1", part 1 => 18;
+        part_2: "day24-1", part 2 => 54;
+    }
+
+    #[test]
+    fn blizzards_repeat() {
+        let valley = crate::solution::parse_example::<Day24>(2022, "day24-1");
+
+        assert_eq!(valley.period, 12);
+        // The blizzard starting at (1, 1) heading right is two tiles further after two minutes.
+        assert!(valley.blizzards[2][(3, 1)]);
+        assert_eq!(valley.blizzards[0].iter().filter(|&(_, &b)| b).count(), 19);
+    }
+}
@@ -0,0 +1,86 @@
+use itertools::Itertools;
+
+use crate::{parse::{self, ParseError}, solution::{Answer, Solution}};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Parsed = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse::lines(input, to_decimal)
+    }
+
+    fn part_1(numbers: &Self::Parsed) -> Answer {
+        let sum: i64 = numbers.iter().sum();
+        to_snafu(sum).into()
+    }
+}
+
+fn to_decimal(line: &str) -> Result<i64, ParseError> {
+    let mut multiple = line.len() as u32;
+    let mut sum = 0;
+    for (i, c) in line.char_indices() {
+        multiple -= 1;
+        sum += match c {
+            '=' => 5_i64.pow(multiple) * -2,
+            '-' => -5_i64.pow(multiple),
+            '0' => 0,
+            '1' => 5_i64.pow(multiple),
+            '2' => 5_i64.pow(multiple) * 2,
+            _ => return Err(ParseError::new("a SNAFU digit").at_column(i + 1))
+        };
+    }
+
+    Ok(sum)
+}
+
+fn to_snafu(n: i64) -> String {
+    let powers = (0..50)
+        .rev()
+        .map(|n| i128::pow(5, n))
+        .collect_vec();
+
+    let mut current = 0;
+    let mut result = String::new();
+
+    for pow in powers {
+        let mut closest = i128::MAX;
+        let mut pick = None;
+        
+        for i in -2..=2 {
+            let test = i * pow + current;
+            let abs_val = (test - n as i128).abs();
+            if abs_val >= closest {
+                continue;
+            };
+            closest = abs_val;
+            pick = Some(i);
+        }
+
+        if let Some(p) = pick {
+            current += p * pow;
+            let ch = match p {
+                2 => '2',
+                1 => '1',
+                0 => '0',
+                -1 => '-',
+                -2 => '=',
+                _ => panic!()
+            };
+            result.push(ch);
+        }
+    }
+
+    result.trim_start_matches('0').into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day25 in 2022;
+        part_1: "day25-1", part 1 => "2=-1=0";
+    }
+}
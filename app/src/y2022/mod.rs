@@ -0,0 +1,55 @@
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+
+use crate::{registry::Day, solution::solve};
+
+pub const DAYS: &[Day] = &[
+    Day { number: 1, title: "Calorie Counting", solve: solve::<day01::Day01> },
+    Day { number: 2, title: "Rock Paper Scissors", solve: solve::<day02::Day02> },
+    Day { number: 3, title: "Rucksack Reorganization", solve: solve::<day03::Day03> },
+    Day { number: 4, title: "Camp Cleanup", solve: solve::<day04::Day04> },
+    Day { number: 5, title: "Supply Stacks", solve: solve::<day05::Day05> },
+    Day { number: 6, title: "Tuning Trouble", solve: solve::<day06::Day06> },
+    Day { number: 7, title: "No Space Left On Device", solve: solve::<day07::Day07> },
+    Day { number: 8, title: "Treetop Tree House", solve: solve::<day08::Day08> },
+    Day { number: 9, title: "Rope Bridge", solve: solve::<day09::Day09> },
+    Day { number: 10, title: "Cathode-Ray Tube", solve: solve::<day10::Day10> },
+    Day { number: 11, title: "Monkey in the Middle", solve: solve::<day11::Day11> },
+    Day { number: 12, title: "Hill Climbing Algorithm", solve: solve::<day12::Day12> },
+    Day { number: 13, title: "Distress Signal", solve: solve::<day13::Day13> },
+    Day { number: 14, title: "Regolith Reservoir", solve: solve::<day14::Day14> },
+    Day { number: 15, title: "Beacon Exclusion Zone", solve: solve::<day15::Day15> },
+    Day { number: 16, title: "Proboscidea Volcanium", solve: solve::<day16::Day16> },
+    Day { number: 17, title: "Pyroclastic Flow", solve: solve::<day17::Day17> },
+    Day { number: 18, title: "Boiling Boulders", solve: solve::<day18::Day18> },
+    Day { number: 19, title: "Not Enough Minerals", solve: solve::<day19::Day19> },
+    Day { number: 20, title: "Grove Positioning System", solve: solve::<day20::Day20> },
+    Day { number: 21, title: "Monkey Math", solve: solve::<day21::Day21> },
+    Day { number: 22, title: "Monkey Map", solve: solve::<day22::Day22> },
+    Day { number: 23, title: "Unstable Diffusion", solve: solve::<day23::Day23> },
+    Day { number: 24, title: "Blizzard Basin", solve: solve::<day24::Day24> },
+    Day { number: 25, title: "Full of Hot Air", solve: solve::<day25::Day25> },
+];